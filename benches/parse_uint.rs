@@ -0,0 +1,60 @@
+//! Compares parse_uint with the byte-wise loop and the standard library.
+//!
+//! The byte-wise loop is the one of parse_uint_const, which parse_uint ran
+//! for every input before taking 8 digits at once in bases 10 and 16.
+//!
+//! Run with `cargo bench --bench parse_uint`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const ROUNDS: usize = 200;
+
+fn main() {
+    let decimals = inputs(|v| format!("{}", v % 10u64.pow(10 + (v % 11) as u32)));
+    let hexes = inputs(|v| format!("{:016x}", v));
+
+    for (name, inputs, base) in [
+        ("decimal ids (10-20 digits)", &decimals, 10),
+        ("hex hashes (16 digits)", &hexes, 16),
+    ] {
+        println!("{}:", name);
+        report("strconv::parse_uint", inputs, |s| {
+            strconv::parse_uint(s, base, 64).unwrap()
+        });
+        report("strconv::parse_uint_const", inputs, |s| {
+            strconv::parse_uint_const(s, base, 64).unwrap()
+        });
+        report("u64::from_str_radix", inputs, |s| {
+            u64::from_str_radix(s, base as u32).unwrap()
+        });
+    }
+}
+
+/// Renders a fixed sequence of pseudo-random numbers by f.
+fn inputs(f: impl Fn(u64) -> String) -> Vec<String> {
+    let mut v = 0x9e37_79b9_7f4a_7c15u64;
+    (0..1000)
+        .map(|_| {
+            v ^= v << 13;
+            v ^= v >> 7;
+            v ^= v << 17;
+            f(v)
+        })
+        .collect()
+}
+
+/// Prints the best time per input of parse over a number of rounds.
+fn report(name: &str, inputs: &[String], parse: impl Fn(&str) -> u64) {
+    let mut best = Duration::MAX;
+    for _ in 0..ROUNDS {
+        let start = Instant::now();
+        for s in inputs {
+            black_box(parse(black_box(s)));
+        }
+        best = best.min(start.elapsed());
+    }
+
+    let per_input = best.as_nanos() as f64 / inputs.len() as f64;
+    println!("  {:<28}{:>8.2} ns/input", name, per_input);
+}
@@ -0,0 +1,9 @@
+fn main() {
+    const V: i64 = -42;
+
+    let s10 = strconv::format_int(V, 10);
+    assert_eq!(s10, "-42");
+
+    let s16 = strconv::format_int(V, 16);
+    assert_eq!(s16, "-2a");
+}
@@ -0,0 +1,9 @@
+fn main() {
+    const V: u64 = 42;
+
+    let s10 = strconv::format_uint(V, 10);
+    assert_eq!(s10, "42");
+
+    let s16 = strconv::format_uint(V, 16);
+    assert_eq!(s16, "2a");
+}
@@ -1,5 +1,5 @@
 fn main() {
-    const V: &'static str = "42";
+    const V: &str = "42";
 
     let got = strconv::parse_uint(V, 10, 32);
     assert_eq!(got, Ok(42));
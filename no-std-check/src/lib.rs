@@ -0,0 +1,66 @@
+//! Crate strconv-no-std-check makes sure strconv builds under `#![no_std]`
+//! without `alloc`.
+//!
+//! Build it on its own with `cargo test -p strconv-no-std-check`, since
+//! building it along with the rest of the workspace unifies the features of
+//! strconv.
+
+#![no_std]
+
+use strconv::{FormatBuffer, NumError, NumErrorCause};
+
+/// Parses a register address.
+pub fn parse_addr(s: &str) -> Result<u32, NumError<'_>> {
+    strconv::parse_uint(s, 0, 32).map(|v| v as u32)
+}
+
+/// Parses an offset in bytes, reporting only why it fails.
+pub fn parse_offset(s: &[u8]) -> Result<i16, NumErrorCause> {
+    strconv::parse_bytes::<i16>(s, 0).map_err(|err| err.err)
+}
+
+/// Formats a register address in hex, as accepted by [parse_addr] along with
+/// a "0x" prefix.
+pub fn format_addr(addr: u32, buf: &mut FormatBuffer) -> &str {
+    buf.format(addr, 16)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        assert_eq!(parse_addr("0x4000_1000"), Ok(0x4000_1000));
+        assert_eq!(parse_offset(b"-0o10"), Ok(-8));
+
+        let err = parse_addr("0x1_0000_0000").unwrap_err();
+        assert_eq!(err.num, "0x1_0000_0000");
+        assert_eq!(
+            err.err,
+            NumErrorCause::OutOfRangeUnsigned {
+                bound_hint: u32::MAX as u64
+            }
+        );
+
+        let err = parse_offset(b"1\xff").unwrap_err();
+        assert_eq!(err, NumErrorCause::InvalidSyntax);
+    }
+
+    #[test]
+    fn format() {
+        let mut buf = FormatBuffer::new();
+        assert_eq!(format_addr(0x4000_1000, &mut buf), "40001000");
+
+        let s = buf.format(-8i16, 8);
+        assert_eq!(s, "-10");
+        assert_eq!(strconv::parse_int(s, 8, 16), Ok(-8));
+    }
+
+    #[test]
+    fn num_of_invalid_utf8() {
+        let err = strconv::parse_int_bytes(b"12\xff3", 10, 64).unwrap_err();
+        assert!(err.num.starts_with("12"));
+        assert_eq!(err.invalid.map(|v| v.offset), Some(2));
+    }
+}
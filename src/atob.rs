@@ -0,0 +1,260 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::NumError;
+
+/// The spellings of booleans accepted by [parse_bool_with].
+///
+/// It's built from the predefined dialects or by [BoolDialect::new] from
+/// custom word lists, which are `'static` so that dialects can be consts.
+///
+/// It displays the accepted spellings, as "true (up) or false (down),
+/// ignoring case", which completes the message of a failed
+/// [parse_bool_with].
+///
+/// # Example
+/// ```
+/// use strconv::BoolDialect;
+///
+/// assert_eq!(strconv::parse_bool_with("yes", BoolDialect::YAML), Ok(true));
+/// assert_eq!(strconv::parse_bool_with("OFF", BoolDialect::ENV), Ok(false));
+///
+/// const SWITCH: BoolDialect = BoolDialect::new(&["up"], &["down"]).ignore_case(true);
+/// assert_eq!(strconv::parse_bool_with("Down", SWITCH), Ok(false));
+///
+/// let err = strconv::parse_bool_with("left", SWITCH).unwrap_err();
+/// assert_eq!(
+///     format!("{}, want {}", err, SWITCH),
+///     "strconv.parse_bool_with: parsing 'left': invalid syntax, \
+///      want true (up) or false (down), ignoring case"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BoolDialect {
+    truthy: &'static [&'static str],
+    falsy: &'static [&'static str],
+    ignore_case: bool,
+}
+
+impl BoolDialect {
+    /// The strict spellings of Go, as accepted by [parse_bool].
+    ///
+    /// [parse_bool_with] fails for it as [parse_bool] does, with
+    /// [NumErrorCause::InvalidSyntax][crate::NumErrorCause::InvalidSyntax], except that `err.func` names
+    /// "parse_bool_with".
+    pub const GO: Self = Self::new(
+        &["1", "t", "T", "TRUE", "true", "True"],
+        &["0", "f", "F", "FALSE", "false", "False"],
+    );
+
+    /// The spellings of the boolean type of YAML 1.1.
+    pub const YAML: Self = Self::new(
+        &[
+            "y", "Y", "yes", "Yes", "YES", "true", "True", "TRUE", "on", "On", "ON",
+        ],
+        &[
+            "n", "N", "no", "No", "NO", "false", "False", "FALSE", "off", "Off", "OFF",
+        ],
+    );
+
+    /// The spellings of INI files such as systemd units, in any case.
+    pub const INI: Self = Self::new(
+        &["1", "yes", "y", "true", "t", "on", "enable"],
+        &["0", "no", "n", "false", "f", "off", "disable"],
+    )
+    .ignore_case(true);
+
+    /// The spellings commonly accepted for flags in environment variables, in
+    /// any case.
+    pub const ENV: Self =
+        Self::new(&["1", "true", "yes", "on"], &["0", "false", "no", "off"]).ignore_case(true);
+
+    /// Returns the dialect accepting the words of truthy for `true` and those
+    /// of falsy for `false`, which are matched exactly. A word in both lists
+    /// means `true`.
+    pub const fn new(truthy: &'static [&'static str], falsy: &'static [&'static str]) -> Self {
+        Self {
+            truthy,
+            falsy,
+            ignore_case: false,
+        }
+    }
+
+    /// Sets whether the words match regardless of ASCII case.
+    pub const fn ignore_case(self, ignore_case: bool) -> Self {
+        Self {
+            ignore_case,
+            ..self
+        }
+    }
+
+    /// Returns the words meaning `true`.
+    pub const fn truthy(&self) -> &'static [&'static str] {
+        self.truthy
+    }
+
+    /// Returns the words meaning `false`.
+    pub const fn falsy(&self) -> &'static [&'static str] {
+        self.falsy
+    }
+
+    fn matches(&self, words: &[&str], s: &[u8]) -> bool {
+        if self.ignore_case {
+            words.iter().any(|w| w.as_bytes().eq_ignore_ascii_case(s))
+        } else {
+            words.iter().any(|w| w.as_bytes() == s)
+        }
+    }
+}
+
+impl fmt::Display for BoolDialect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn words(f: &mut fmt::Formatter<'_>, words: &[&str]) -> fmt::Result {
+            for (i, w) in words.iter().enumerate() {
+                if i != 0 {
+                    f.write_str(", ")?;
+                }
+                f.write_str(w)?;
+            }
+            Ok(())
+        }
+
+        f.write_str("true (")?;
+        words(f, self.truthy)?;
+        f.write_str(") or false (")?;
+        words(f, self.falsy)?;
+        f.write_str(")")?;
+        if self.ignore_case {
+            f.write_str(", ignoring case")?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for BoolDialect {
+    fn default() -> Self {
+        Self::GO
+    }
+}
+
+/// Returns the boolean value represented by the string.
+///
+/// It accepts 1, t, T, TRUE, true, True, 0, f, F, FALSE, false, False.
+/// Any other value returns an error with [NumErrorCause::InvalidSyntax][crate::NumErrorCause::InvalidSyntax].
+///
+/// Use [parse_bool_with] for other spellings.
+///
+/// # Example
+/// ```
+/// assert_eq!(strconv::parse_bool("true"), Ok(true));
+/// assert_eq!(strconv::parse_bool("F"), Ok(false));
+///
+/// let err = strconv::parse_bool("yes").unwrap_err();
+/// assert_eq!(err.to_string(), "strconv.parse_bool: parsing 'yes': invalid syntax");
+/// ```
+pub fn parse_bool(s: &str) -> Result<bool, NumError<'_>> {
+    parse_bool_bytes(s.as_bytes())
+}
+
+/// Like [parse_bool] but reads the value from a byte slice, which isn't
+/// required to be valid UTF-8.
+///
+/// `err.num` is the input rendered as [NumStr][crate::NumStr], which is lossy
+/// for invalid UTF-8.
+///
+/// # Example
+/// ```
+/// assert_eq!(strconv::parse_bool_bytes(b"True"), Ok(true));
+///
+/// let err = strconv::parse_bool_bytes(b"t\xff").unwrap_err();
+/// assert_eq!(err.err, strconv::NumErrorCause::InvalidSyntax);
+/// ```
+pub fn parse_bool_bytes(s: &[u8]) -> Result<bool, NumError<'_>> {
+    const FN_PARSE_BOOL: &str = "parse_bool";
+
+    match s {
+        b"1" | b"t" | b"T" | b"TRUE" | b"true" | b"True" => Ok(true),
+        b"0" | b"f" | b"F" | b"FALSE" | b"false" | b"False" => Ok(false),
+        _ => Err(NumError::syntax(FN_PARSE_BOOL, s)),
+    }
+}
+
+/// Returns the boolean value represented by the string in the given dialect.
+///
+/// Any value not accepted by the dialect returns an error with
+/// [NumErrorCause::InvalidSyntax][crate::NumErrorCause::InvalidSyntax], as [parse_bool] does. The accepted
+/// spellings are listed by the [Display][fmt::Display] of the dialect.
+///
+/// # Example
+/// ```
+/// use strconv::BoolDialect;
+///
+/// assert_eq!(strconv::parse_bool_with("Enable", BoolDialect::INI), Ok(true));
+///
+/// let err = strconv::parse_bool_with("yes", BoolDialect::GO).unwrap_err();
+/// assert_eq!(err.err, strconv::NumErrorCause::InvalidSyntax);
+/// assert_eq!(
+///     format!("{}, want {}", err, BoolDialect::GO),
+///     "strconv.parse_bool_with: parsing 'yes': invalid syntax, \
+///      want true (1, t, T, TRUE, true, True) or false (0, f, F, FALSE, false, False)"
+/// );
+/// ```
+pub fn parse_bool_with(s: &str, dialect: BoolDialect) -> Result<bool, NumError<'_>> {
+    parse_bool_with_bytes(s.as_bytes(), dialect)
+}
+
+/// Like [parse_bool_with] but reads the value from a byte slice, which isn't
+/// required to be valid UTF-8.
+///
+/// `err.num` is the input rendered as [NumStr][crate::NumStr], which is lossy
+/// for invalid UTF-8.
+///
+/// # Example
+/// ```
+/// use strconv::BoolDialect;
+///
+/// assert_eq!(strconv::parse_bool_with_bytes(b"OFF", BoolDialect::ENV), Ok(false));
+///
+/// let err = strconv::parse_bool_with_bytes(b"\xc3\x28", BoolDialect::ENV).unwrap_err();
+/// assert_eq!(err.err, strconv::NumErrorCause::InvalidSyntax);
+/// ```
+pub fn parse_bool_with_bytes(s: &[u8], dialect: BoolDialect) -> Result<bool, NumError<'_>> {
+    const FN_PARSE_BOOL_WITH: &str = "parse_bool_with";
+
+    if dialect.matches(dialect.truthy, s) {
+        Ok(true)
+    } else if dialect.matches(dialect.falsy, s) {
+        Ok(false)
+    } else {
+        Err(NumError::syntax(FN_PARSE_BOOL_WITH, s))
+    }
+}
+
+/// Returns "true" or "false" according to the value of b.
+///
+/// # Example
+/// ```
+/// assert_eq!(strconv::format_bool(true), "true");
+/// assert_eq!(strconv::parse_bool(strconv::format_bool(false)), Ok(false));
+/// ```
+pub const fn format_bool(b: bool) -> &'static str {
+    if b {
+        "true"
+    } else {
+        "false"
+    }
+}
+
+/// Appends "true" or "false", according to the value of b, to dst.
+///
+/// # Example
+/// ```
+/// let mut b = b"bool:".to_vec();
+/// strconv::append_bool(&mut b, true);
+/// assert_eq!(b, b"bool:true");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn append_bool(dst: &mut Vec<u8>, b: bool) {
+    dst.extend_from_slice(format_bool(b).as_bytes());
+}
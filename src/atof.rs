@@ -0,0 +1,555 @@
+use crate::atoi::{digit, underscore_ok};
+use crate::decimal::{Decimal, FloatInfo, FLOAT32_INFO, FLOAT64_INFO};
+use crate::{NumError, NumErrorCause};
+
+/// Exact powers of 10 as f64.
+const FLOAT64_POW10: [f64; 23] = [
+    1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10, 1e11, 1e12, 1e13, 1e14, 1e15, 1e16,
+    1e17, 1e18, 1e19, 1e20, 1e21, 1e22,
+];
+
+/// Exact powers of 10 as f32.
+const FLOAT32_POW10: [f32; 11] = [1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10];
+
+/// The count of decimal digits which always fit into the u64 mantissa.
+const MAX_MANT_DIGITS: usize = 19;
+
+/// The count of hexadecimal digits which always fit into the u64 mantissa.
+const MAX_MANT_HEX_DIGITS: usize = 16;
+
+/// Converts the string s to a floating-point number with the precision
+/// specified by `bit_size`: 32 for f32, or 64 for f64. When `bit_size` is 32,
+/// the result still has type f64, but it converts to f32 without changing its
+/// value.
+///
+/// The string holds a decimal or hexadecimal number as defined by the Go
+/// syntax for [floating-point literals], following an optional sign:
+/// - decimal digits with an optional decimal point, and an optional exponent
+///   of 10 after "e" or "E", as in "1.5e-3";
+/// - "0x" or "0X", hexadecimal digits with an optional point, and a mandatory
+///   exponent of 2 after "p" or "P", as in "0x1.8p3".
+///
+/// Underscores may separate digits, or follow the "0x" prefix, as in the
+/// literals. The special values are spelled "inf" or "infinity" with an
+/// optional sign, and "nan" without any sign, in any case.
+///
+/// If s is well-formed, the result is the floating-point number nearest to
+/// its value, rounding half to even as IEEE 754 does. For `bit_size` 32, it's
+/// rounded to f32 directly rather than by way of f64, so it's never rounded
+/// twice. Hexadecimal numbers are only rounded if they carry more bits than
+/// the precision. Values too small for the precision become zero without an
+/// error.
+///
+/// The errors that `parse_float` returns have concrete type
+/// [NumError][crate::NumError] and include `err.num = s`. If s is
+/// syntactically invalid, `err.err` =
+/// [NumErrorCause::InvalidSyntax][crate::NumErrorCause::InvalidSyntax], with
+/// `err.invalid` locating the first invalid character or misplaced
+/// underscore. For a near-miss of a special value, that's the first
+/// character deviating from its spelling, as the 'x' in "infinitx", or the
+/// first character after "inf" if s ends within "infinity", as the second
+/// 'i' in "infinit". If s ends before any special value is spelled, as "in",
+/// no character is located. If s is well-formed but rounds beyond the
+/// largest finite number of the given size, `err.err` =
+/// [NumErrorCause::OutOfRangeFloat][crate::NumErrorCause::OutOfRangeFloat]
+/// with the infinity of the sign of s as hint.
+/// If `bit_size` is neither 32 nor 64, `err.err` =
+/// [NumErrorCause::InvalidBitSize][crate::NumErrorCause::InvalidBitSize].
+///
+/// # Example
+/// ```
+/// assert_eq!(strconv::parse_float("3.1415926535", 64), Ok(3.1415926535));
+/// assert_eq!(strconv::parse_float("-1_000.5e-3", 64), Ok(-1.0005));
+/// assert_eq!(strconv::parse_float("3.4028235e38", 32), Ok(f32::MAX as f64));
+/// assert_eq!(strconv::parse_float("0x1.8p3", 64), Ok(12.0));
+/// assert_eq!(strconv::parse_float("-0X.8P-2", 64), Ok(-0.125));
+/// assert_eq!(strconv::parse_float("-Infinity", 32), Ok(f64::NEG_INFINITY));
+/// assert!(strconv::parse_float("NaN", 64).unwrap().is_nan());
+///
+/// let err = strconv::parse_float("1e309", 64).unwrap_err();
+/// assert_eq!(
+///     err.err,
+///     strconv::NumErrorCause::OutOfRangeFloat { negative: false }
+/// );
+/// assert_eq!(err.err.bound_hint(), Some(f64::INFINITY));
+///
+/// let err = strconv::parse_float("infinitx", 64).unwrap_err();
+/// assert_eq!(err.invalid.map(|v| (v.offset, v.ch)), Some((7, 'x')));
+/// ```
+///
+/// [floating-point literals]: https://go.dev/ref/spec#Floating-point_literals
+pub fn parse_float(s: &str, bit_size: u8) -> Result<f64, NumError<'_>> {
+    parse_float_bytes(s.as_bytes(), bit_size)
+}
+
+/// Like [parse_float] but reads the number from a byte slice, which isn't
+/// required to be valid UTF-8.
+///
+/// # Example
+/// ```
+/// assert_eq!(strconv::parse_float_bytes(b"0.1", 32), Ok(0.1f32 as f64));
+///
+/// let err = strconv::parse_float_bytes(b"1.5\xff", 64).unwrap_err();
+/// assert_eq!(err.invalid.map(|v| v.offset), Some(3));
+/// ```
+pub fn parse_float_bytes(s: &[u8], bit_size: u8) -> Result<f64, NumError<'_>> {
+    const FN_PARSE_FLOAT: &str = "parse_float";
+
+    let flt = float_info(FN_PARSE_FLOAT, s, bit_size)?;
+    let prefix = read_prefix(s, flt).map_err(|v| syntax_error(FN_PARSE_FLOAT, s, v))?;
+    if prefix.len < s.len() {
+        // A near-miss of "infinity", as "infinitx", fails at the deviating
+        // byte, but at the end of "inf" if s spells a part of it only.
+        let offset = if prefix.matched < s.len() {
+            prefix.matched
+        } else {
+            prefix.len
+        };
+        return Err(NumError::syntax_at(FN_PARSE_FLOAT, s, offset));
+    }
+
+    if prefix.overflow {
+        let cause = NumErrorCause::OutOfRangeFloat {
+            negative: prefix.value.is_sign_negative(),
+        };
+        return Err(NumError::new(FN_PARSE_FLOAT, s, cause));
+    }
+    Ok(prefix.value)
+}
+
+/// Like [parse_float] but parses only the number at the start of s, returning
+/// the value and the count of bytes it takes up.
+///
+/// Parsing stops at the first byte which can't continue the number, so s may
+/// carry anything after the number. A special value takes up its longest
+/// spelling, e.g. 3 bytes of "info" and 8 bytes of "infinity0", but the
+/// number must be complete otherwise: "1e" or "0x1" without the exponent are
+/// errors.
+///
+/// If no number is found, `err.err` =
+/// [NumErrorCause::InvalidSyntax][crate::NumErrorCause::InvalidSyntax].
+/// Range errors are reported as by [parse_float], and `err.num` is the whole
+/// s in both cases.
+///
+/// # Example
+/// ```
+/// assert_eq!(strconv::parse_float_prefix("1.5e3ms", 64), Ok((1500.0, 5)));
+/// assert_eq!(strconv::parse_float_prefix("-inf,", 64), Ok((f64::NEG_INFINITY, 4)));
+/// assert_eq!(strconv::parse_float_prefix("infinite", 64), Ok((f64::INFINITY, 3)));
+///
+/// let (v, n) = strconv::parse_float_prefix("nan]", 32).unwrap();
+/// assert!(v.is_nan());
+/// assert_eq!(n, 3);
+/// ```
+pub fn parse_float_prefix(s: &str, bit_size: u8) -> Result<(f64, usize), NumError<'_>> {
+    const FN_PARSE_FLOAT_PREFIX: &str = "parse_float_prefix";
+
+    let s = s.as_bytes();
+    let flt = float_info(FN_PARSE_FLOAT_PREFIX, s, bit_size)?;
+    let prefix = read_prefix(s, flt).map_err(|v| syntax_error(FN_PARSE_FLOAT_PREFIX, s, v))?;
+
+    if prefix.overflow {
+        let cause = NumErrorCause::OutOfRangeFloat {
+            negative: prefix.value.is_sign_negative(),
+        };
+        return Err(NumError::new(FN_PARSE_FLOAT_PREFIX, s, cause));
+    }
+    Ok((prefix.value, prefix.len))
+}
+
+fn float_info<'a>(
+    func: &'static str,
+    s: &'a [u8],
+    bit_size: u8,
+) -> Result<&'static FloatInfo, NumError<'a>> {
+    match bit_size {
+        32 => Ok(&FLOAT32_INFO),
+        64 => Ok(&FLOAT64_INFO),
+        _ => Err(NumError::bit_size(func, s, bit_size)),
+    }
+}
+
+/// Returns the syntax error locating the invalid character at offset, if
+/// any.
+fn syntax_error<'a>(func: &'static str, s: &'a [u8], offset: Option<usize>) -> NumError<'a> {
+    match offset {
+        Some(offset) => NumError::syntax_at(func, s, offset),
+        None => NumError::syntax(func, s),
+    }
+}
+
+/// A float read from the start of an input by [read_prefix].
+struct Prefix {
+    value: f64,
+    /// whether the value overflows, in which case it's an infinity
+    overflow: bool,
+    /// the count of bytes the float takes
+    len: usize,
+    /// the count of bytes matching the spelling of a special value, which
+    /// runs beyond len for near-misses such as "infinit", or len otherwise
+    matched: usize,
+}
+
+/// Reads the float of flt at the start of s.
+///
+/// The error carries the offset of the invalid character or misplaced
+/// underscore, or `None` if s ends before the number is complete.
+fn read_prefix(s: &[u8], flt: &FloatInfo) -> Result<Prefix, Option<usize>> {
+    if let Some(v) = special(s) {
+        return v;
+    }
+
+    let parts = read_float(s)?;
+    let (value, overflow) = atof(&s[..parts.len], &parts, flt);
+    Ok(Prefix {
+        value,
+        overflow,
+        len: parts.len,
+        matched: parts.len,
+    })
+}
+
+/// Reads the special value at the start of s, which is an infinity spelled
+/// "inf" or "infinity" with an optional sign, or NaN spelled "nan", in any
+/// case. It returns `None` if s doesn't start like one.
+fn special(s: &[u8]) -> Option<Result<Prefix, Option<usize>>> {
+    let (sign, neg) = match s.first() {
+        Some(b'+') => (1, false),
+        Some(b'-') => (1, true),
+        _ => (0, false),
+    };
+
+    let rest = &s[sign..];
+    let (word, value): (&[u8], _) = match rest.first()?.to_ascii_lowercase() {
+        b'i' if neg => (b"infinity", f64::NEG_INFINITY),
+        b'i' => (b"infinity", f64::INFINITY),
+        b'n' if sign == 0 => (b"nan", f64::NAN),
+        _ => return None,
+    };
+
+    let n = rest
+        .iter()
+        .zip(word)
+        .take_while(|(c, w)| c.eq_ignore_ascii_case(w))
+        .count();
+    let len = match n {
+        // "inf", "infinity" or "nan"
+        3 | 8 => n,
+        // Anything longer than "inf" is fine, but without "infinity", only
+        // "inf" is taken.
+        4..=7 => 3,
+        _ => return Some(Err((n < rest.len()).then_some(sign + n))),
+    };
+
+    Some(Ok(Prefix {
+        value,
+        overflow: false,
+        len: sign + len,
+        matched: sign + n,
+    }))
+}
+
+/// The leading floating-point number of an input, as read by [read_float].
+struct FloatParts {
+    /// the leading digits, up to [MAX_MANT_DIGITS] of them, or
+    /// [MAX_MANT_HEX_DIGITS] for hexadecimal numbers
+    mantissa: u64,
+    /// the exponent of 10 scaling the mantissa, or of 2 for hexadecimal
+    /// numbers
+    exp: i32,
+    neg: bool,
+    /// whether nonzero digits were dropped from the mantissa
+    trunc: bool,
+    hex: bool,
+    /// the count of bytes the number takes
+    len: usize,
+}
+
+/// Reads the floating-point number at the start of s.
+///
+/// The error carries the offset of the invalid character or misplaced
+/// underscore, or `None` if s ends before the number is complete.
+fn read_float(s: &[u8]) -> Result<FloatParts, Option<usize>> {
+    let mut i = 0;
+    let mut neg = false;
+
+    // Optional sign.
+    match s.first() {
+        Some(b'+') => i += 1,
+        Some(b'-') => {
+            neg = true;
+            i += 1;
+        }
+        _ => {}
+    }
+
+    let mut base = 10;
+    let mut max_mant_digits = MAX_MANT_DIGITS;
+    let mut exp_char = b'e';
+    let hex = (s.len() > i + 2) && (s[i] == b'0') && s[i + 1].eq_ignore_ascii_case(&b'x');
+    if hex {
+        base = 16;
+        max_mant_digits = MAX_MANT_HEX_DIGITS;
+        exp_char = b'p';
+        i += 2;
+    }
+
+    // Digits.
+    let mut underscores = false;
+    let mut saw_dot = false;
+    let mut saw_digits = false;
+    let mut nd = 0;
+    let mut nd_mant = 0;
+    let mut dp = 0i32;
+    let mut mantissa = 0u64;
+    let mut trunc = false;
+    while let Some(&c) = s.get(i) {
+        match c {
+            b'_' => underscores = true,
+            b'.' if !saw_dot => {
+                saw_dot = true;
+                dp = nd;
+            }
+            b'0' if nd == 0 => {
+                // Ignore leading zeros.
+                saw_digits = true;
+                dp -= 1;
+            }
+            _ if digit(c) < base => {
+                saw_digits = true;
+                nd += 1;
+                if nd_mant < max_mant_digits {
+                    mantissa = mantissa * base as u64 + digit(c) as u64;
+                    nd_mant += 1;
+                } else if c != b'0' {
+                    trunc = true;
+                }
+            }
+            _ => break,
+        }
+        i += 1;
+    }
+
+    if !saw_digits {
+        return Err((i < s.len()).then_some(i));
+    }
+    if !saw_dot {
+        dp = nd;
+    }
+    if hex {
+        dp *= 4;
+        nd_mant *= 4;
+    }
+
+    // Optional exponent, which is mandatory for hexadecimal numbers.
+    if s.get(i)
+        .map_or(false, |c| c.eq_ignore_ascii_case(&exp_char))
+    {
+        i += 1;
+        let mut e_neg = false;
+        match s.get(i) {
+            Some(b'+') => i += 1,
+            Some(b'-') => {
+                e_neg = true;
+                i += 1;
+            }
+            _ => {}
+        }
+
+        match s.get(i) {
+            Some(b'0'..=b'9') => {}
+            Some(_) => return Err(Some(i)),
+            None => return Err(None),
+        }
+
+        let mut e = 0i32;
+        while let Some(&c) = s.get(i) {
+            match c {
+                b'_' => underscores = true,
+                b'0'..=b'9' if e < 10000 => e = e * 10 + (c - b'0') as i32,
+                b'0'..=b'9' => {}
+                _ => break,
+            }
+            i += 1;
+        }
+        dp += if e_neg { -e } else { e };
+    } else if hex {
+        return Err((i < s.len()).then_some(i));
+    }
+
+    if underscores {
+        underscore_ok(&s[..i]).map_err(Some)?;
+    }
+
+    Ok(FloatParts {
+        mantissa,
+        exp: if mantissa != 0 {
+            dp - nd_mant as i32
+        } else {
+            0
+        },
+        neg,
+        trunc,
+        hex,
+        len: i,
+    })
+}
+
+/// Converts the number s read as parts into the float of flt, and reports
+/// whether it overflows, in which case the result is an infinity.
+fn atof(s: &[u8], parts: &FloatParts, flt: &FloatInfo) -> (f64, bool) {
+    if parts.hex {
+        return atof_hex(parts, flt);
+    }
+
+    // Try pure floating-point arithmetic, which is exact for short mantissas
+    // and small exponents.
+    if !parts.trunc {
+        let exact = if flt.mant_bits == FLOAT32_INFO.mant_bits {
+            atof32_exact(parts.mantissa, parts.exp, parts.neg).map(f64::from)
+        } else {
+            atof64_exact(parts.mantissa, parts.exp, parts.neg)
+        };
+        if let Some(f) = exact {
+            return (f, false);
+        }
+    }
+
+    // Slow fallback.
+    let (bits, overflow) = Decimal::new(s).float_bits(flt);
+    (from_bits(bits, flt), overflow)
+}
+
+/// Converts the hexadecimal number read as parts into the float of flt, and
+/// reports whether it overflows, in which case the result is an infinity.
+fn atof_hex(parts: &FloatParts, flt: &FloatInfo) -> (f64, bool) {
+    let max_exp = (1 << flt.exp_bits) + flt.bias - 2;
+    let min_exp = flt.bias + 1;
+
+    // The mantissa is now implicitly divided by 2^mant_bits.
+    let mut mantissa = parts.mantissa;
+    let mut exp = parts.exp + flt.mant_bits as i32;
+
+    // Shift the mantissa and the exponent into the float range, which makes
+    // the mantissa a leading 1 followed by mant_bits other bits. Rounding
+    // takes two more, where the lowest one records whether any later bit is
+    // set, including the bits lost before (if trunc is set).
+    while (mantissa != 0) && (mantissa >> (flt.mant_bits + 2) == 0) {
+        mantissa <<= 1;
+        exp -= 1;
+    }
+    if parts.trunc {
+        mantissa |= 1;
+    }
+    while mantissa >> (1 + flt.mant_bits + 2) != 0 {
+        mantissa = (mantissa >> 1) | (mantissa & 1);
+        exp += 1;
+    }
+
+    // If the exponent is too negative, denormalize in hopes of making it
+    // representable. The -2 is for the rounding bits.
+    while (mantissa > 1) && (exp < min_exp - 2) {
+        mantissa = (mantissa >> 1) | (mantissa & 1);
+        exp += 1;
+    }
+
+    // Round by the two lowest bits, half to even.
+    let mut round = mantissa & 3;
+    mantissa >>= 2;
+    round |= mantissa & 1;
+    exp += 2;
+    if round == 3 {
+        mantissa += 1;
+        if mantissa == 1 << (1 + flt.mant_bits) {
+            mantissa >>= 1;
+            exp += 1;
+        }
+    }
+
+    if mantissa >> flt.mant_bits == 0 {
+        // Denormal or zero.
+        exp = flt.bias;
+    }
+    let overflow = exp > max_exp;
+    if overflow {
+        // ±Inf
+        mantissa = 1 << flt.mant_bits;
+        exp = max_exp + 1;
+    }
+
+    let mut bits = mantissa & ((1 << flt.mant_bits) - 1);
+    bits |= (((exp - flt.bias) as u64) & ((1 << flt.exp_bits) - 1)) << flt.mant_bits;
+    if parts.neg {
+        bits |= 1 << flt.mant_bits << flt.exp_bits;
+    }
+    (from_bits(bits, flt), overflow)
+}
+
+/// Returns the float of flt with the given bits as f64.
+fn from_bits(bits: u64, flt: &FloatInfo) -> f64 {
+    if flt.mant_bits == FLOAT32_INFO.mant_bits {
+        f64::from(f32::from_bits(bits as u32))
+    } else {
+        f64::from_bits(bits)
+    }
+}
+
+/// Returns mantissa * 10^exp if it's computed exactly by f64 arithmetic,
+/// which requires both to be exact f64 values.
+fn atof64_exact(mantissa: u64, exp: i32, neg: bool) -> Option<f64> {
+    if mantissa >> FLOAT64_INFO.mant_bits != 0 {
+        return None;
+    }
+
+    let mut f = mantissa as f64;
+    if neg {
+        f = -f;
+    }
+    match exp {
+        0 => Some(f),
+        1..=37 => {
+            // An int times 10^exp. If the exponent is big but the number of
+            // digits isn't, a few zeros can move into the int.
+            let mut exp = exp as usize;
+            if exp > 22 {
+                f *= FLOAT64_POW10[exp - 22];
+                exp = 22;
+            }
+            if !(-1e15..=1e15).contains(&f) {
+                // The int isn't exact anymore.
+                return None;
+            }
+            Some(f * FLOAT64_POW10[exp])
+        }
+        -22..=-1 => Some(f / FLOAT64_POW10[-exp as usize]),
+        _ => None,
+    }
+}
+
+/// Like [atof64_exact] but for f32.
+fn atof32_exact(mantissa: u64, exp: i32, neg: bool) -> Option<f32> {
+    if mantissa >> FLOAT32_INFO.mant_bits != 0 {
+        return None;
+    }
+
+    let mut f = mantissa as f32;
+    if neg {
+        f = -f;
+    }
+    match exp {
+        0 => Some(f),
+        1..=17 => {
+            let mut exp = exp as usize;
+            if exp > 10 {
+                f *= FLOAT32_POW10[exp - 10];
+                exp = 10;
+            }
+            if !(-1e7..=1e7).contains(&f) {
+                return None;
+            }
+            Some(f * FLOAT32_POW10[exp])
+        }
+        -10..=-1 => Some(f / FLOAT32_POW10[-exp as usize]),
+        _ => None,
+    }
+}
@@ -1,3 +1,7 @@
+use core::ops::{Add, Div, Mul, Rem, Shl, Shr, Sub};
+
+use crate::errors::num_str;
+use crate::swar;
 use crate::{NumError, NumErrorCause};
 
 /// Interprets a string s in the given base (0, 2 to 36) and
@@ -18,7 +22,8 @@ use crate::{NumError, NumErrorCause};
 ///
 /// The errors that `parse_int` returns have concrete type [NumError][crate::NumError]
 /// and include `err.num = s`. If `s` is empty or contains invalid
-/// digits, `err.err` = [NumErrorCause::InvalidSyntax][crate::NumErrorCause::InvalidSyntax] and the returned value is 0;
+/// digits, `err.err` = [NumErrorCause::InvalidSyntax][crate::NumErrorCause::InvalidSyntax] and the returned value is 0,
+/// with `err.invalid` locating the first invalid digit or misplaced underscore;
 /// if the value corresponding to s cannot be represented by a
 /// signed integer of the given size, `err.err` = [NumErrorCause::OutOfRangeSigned][crate::NumErrorCause::OutOfRangeSigned]
 /// and the returned value is the maximum magnitude integer of the
@@ -30,25 +35,372 @@ use crate::{NumError, NumErrorCause};
 /// ```
 ///
 /// [integer literals]: https://go.dev/ref/spec#Integer_literals
-pub fn parse_int(s: &'static str, base: u8, bit_size: u8) -> Result<i64, NumError> {
-    const FN_PARSE_INT: &'static str = "parse_int";
+pub fn parse_int(s: &str, base: u8, bit_size: u8) -> Result<i64, NumError<'_>> {
+    parse_int_bytes(s.as_bytes(), base, bit_size)
+}
+
+/// Like [parse_int] but reads the number from a byte slice, which isn't
+/// required to be valid UTF-8.
+///
+/// Bytes outside the digit set of the base (including any non-UTF-8 byte)
+/// are reported as [NumErrorCause::InvalidSyntax][crate::NumErrorCause::InvalidSyntax],
+/// and `err.num` is the input rendered as [NumStr][crate::NumStr], which is
+/// lossy for invalid UTF-8.
+///
+/// # Example
+/// ```
+/// let got = strconv::parse_int_bytes(b"-0x1f", 0, 64).unwrap();
+/// assert_eq!(got, -0x1f);
+///
+/// let err = strconv::parse_int_bytes(b"12\xff", 10, 64).unwrap_err();
+/// assert_eq!(err.err, strconv::NumErrorCause::InvalidSyntax);
+/// assert_eq!(err.invalid.map(|v| v.offset), Some(2));
+/// ```
+pub fn parse_int_bytes(s: &[u8], base: u8, bit_size: u8) -> Result<i64, NumError<'_>> {
+    const FN_PARSE_INT: &str = "parse_int";
+
+    parse_signed::<u64>(FN_PARSE_INT, s, base, bit_size, false).map(|(v, _)| v)
+}
+
+/// Like [parse_int] but returns a 128-bit value, so that `bit_size` may be
+/// up to 128.
+///
+/// Range errors are reported as
+/// [NumErrorCause::OutOfRangeSigned128][crate::NumErrorCause::OutOfRangeSigned128].
+///
+/// # Example
+/// ```
+/// let got = strconv::parse_int128("-0x8000_0000_0000_0000_0000_0000_0000_0000", 0, 128);
+/// assert_eq!(got, Ok(i128::MIN));
+/// ```
+pub fn parse_int128(s: &str, base: u8, bit_size: u8) -> Result<i128, NumError<'_>> {
+    parse_int128_bytes(s.as_bytes(), base, bit_size)
+}
+
+/// Like [parse_int128] but reads the number from a byte slice, which isn't
+/// required to be valid UTF-8.
+pub fn parse_int128_bytes(s: &[u8], base: u8, bit_size: u8) -> Result<i128, NumError<'_>> {
+    const FN_PARSE_INT128: &str = "parse_int128";
+
+    parse_signed::<u128>(FN_PARSE_INT128, s, base, bit_size, false).map(|(v, _)| v)
+}
+
+/// Like [parse_int] but for unsigned numbers.
+///
+/// A sign prefix is not permitted.
+///
+/// # Example
+/// ```
+#[doc = include_str!("../examples/parse_uint.rs")]
+/// ```
+pub fn parse_uint(s: &str, base: u8, bit_size: u8) -> Result<u64, NumError<'_>> {
+    parse_uint_bytes(s.as_bytes(), base, bit_size)
+}
+
+/// Like [parse_uint] but reads the number from a byte slice, which isn't
+/// required to be valid UTF-8.
+///
+/// See [parse_int_bytes] for how invalid bytes are reported.
+///
+/// # Example
+/// ```
+/// let got = strconv::parse_uint_bytes(b"0o377", 0, 8);
+/// assert_eq!(got, Ok(255));
+/// ```
+pub fn parse_uint_bytes(s: &[u8], base: u8, bit_size: u8) -> Result<u64, NumError<'_>> {
+    const FN_PARSE_UINT: &str = "parse_uint";
+
+    parse_unsigned(FN_PARSE_UINT, s, base, bit_size, false).map(|(v, _)| v)
+}
+
+/// Like [parse_uint] but returns a 128-bit value, so that `bit_size` may be
+/// up to 128.
+///
+/// Range errors are reported as
+/// [NumErrorCause::OutOfRangeUnsigned128][crate::NumErrorCause::OutOfRangeUnsigned128].
+///
+/// # Example
+/// ```
+/// let got = strconv::parse_uint128("0xffff_ffff_ffff_ffff_ffff_ffff_ffff_ffff", 0, 128);
+/// assert_eq!(got, Ok(u128::MAX));
+/// ```
+pub fn parse_uint128(s: &str, base: u8, bit_size: u8) -> Result<u128, NumError<'_>> {
+    parse_uint128_bytes(s.as_bytes(), base, bit_size)
+}
+
+/// Like [parse_uint128] but reads the number from a byte slice, which isn't
+/// required to be valid UTF-8.
+pub fn parse_uint128_bytes(s: &[u8], base: u8, bit_size: u8) -> Result<u128, NumError<'_>> {
+    const FN_PARSE_UINT128: &str = "parse_uint128";
+
+    parse_unsigned(FN_PARSE_UINT128, s, base, bit_size, false).map(|(v, _)| v)
+}
+
+/// Like [parse_int] but parses only the number at the start of s, returning the
+/// value and the count of bytes it takes up.
+///
+/// Parsing stops at the first byte which can't continue the number, so s may
+/// carry anything after the number. For base 0, a "0b", "0o" or "0x" prefix
+/// only counts if it's followed by a digit of the base or an underscore;
+/// otherwise the number is the leading "0". Underscores are validated for the
+/// consumed bytes only, and they must stay within the number, e.g. "1_000_"
+/// is an error.
+///
+/// If no digit is found, `err.err` = [NumErrorCause::InvalidSyntax][crate::NumErrorCause::InvalidSyntax].
+/// Range errors are reported as by [parse_int], and `err.num` is the whole s
+/// in both cases.
+///
+/// # Example
+/// ```
+/// let got = strconv::parse_int_prefix("-123abc", 10, 64);
+/// assert_eq!(got, Ok((-123, 4)));
+///
+/// let got = strconv::parse_int_prefix("0x1F)", 0, 64);
+/// assert_eq!(got, Ok((0x1f, 4)));
+/// ```
+pub fn parse_int_prefix(s: &str, base: u8, bit_size: u8) -> Result<(i64, usize), NumError<'_>> {
+    const FN_PARSE_INT_PREFIX: &str = "parse_int_prefix";
+
+    parse_signed::<u64>(FN_PARSE_INT_PREFIX, s.as_bytes(), base, bit_size, true)
+}
+
+/// Like [parse_int_prefix] but for unsigned numbers.
+///
+/// # Example
+/// ```
+/// let got = strconv::parse_uint_prefix("0b1012", 0, 8);
+/// assert_eq!(got, Ok((5, 5)));
+/// ```
+pub fn parse_uint_prefix(s: &str, base: u8, bit_size: u8) -> Result<(u64, usize), NumError<'_>> {
+    const FN_PARSE_UINT_PREFIX: &str = "parse_uint_prefix";
+
+    parse_unsigned(FN_PARSE_UINT_PREFIX, s.as_bytes(), base, bit_size, true)
+}
+
+/// Like [parse_int] but takes the digits as the two's complement bit pattern
+/// of a signed integer of `bit_size` bits, which is sign-extended into the
+/// result. So "0xFFFFFFFF" is -1 for `bit_size` 32, but 4294967295 for 64.
+///
+/// The digits are parsed as by [parse_uint], including the base 0 prefix
+/// rules, and a sign is not permitted. If they don't fit into `bit_size` bits,
+/// `err.err` = [NumErrorCause::OutOfRangeUnsigned][crate::NumErrorCause::OutOfRangeUnsigned]
+/// with the largest bit pattern as hint.
+///
+/// # Example
+/// ```
+/// assert_eq!(strconv::parse_twos_complement("0xFFFFFFFF", 0, 32), Ok(-1));
+/// assert_eq!(strconv::parse_twos_complement("0x7FFFFFFF", 0, 32), Ok(i32::MAX as i64));
+/// assert_eq!(strconv::parse_twos_complement("0b1000_0000", 0, 8), Ok(-128));
+///
+/// let err = strconv::parse_twos_complement("0x1_0000", 0, 16).unwrap_err();
+/// assert_eq!(err.err.bound_hint::<u16>(), Some(0xffff));
+/// ```
+pub fn parse_twos_complement(s: &str, base: u8, bit_size: u8) -> Result<i64, NumError<'_>> {
+    parse_twos_complement_bytes(s.as_bytes(), base, bit_size)
+}
+
+/// Like [parse_twos_complement] but reads the number from a byte slice, which
+/// isn't required to be valid UTF-8.
+pub fn parse_twos_complement_bytes(s: &[u8], base: u8, bit_size: u8) -> Result<i64, NumError<'_>> {
+    const FN_PARSE_TWOS_COMPLEMENT: &str = "parse_twos_complement";
+
+    let (u, _) = parse_unsigned::<u64>(FN_PARSE_TWOS_COMPLEMENT, s, base, bit_size, false)?;
+
+    let bit_size = if bit_size == 0 {
+        usize::BITS
+    } else {
+        bit_size as u32
+    };
+
+    // Move the sign bit up to bit 63, and back with sign extension.
+    let shift = u64::BITS - bit_size;
+    Ok(((u << shift) as i64) >> shift)
+}
+
+/// Equivalent to [parse_int]\(s, 10, 0), converted to isize.
+///
+/// Errors report `err.func = "atoi"`.
+///
+/// # Example
+/// ```
+/// assert_eq!(strconv::atoi("-42"), Ok(-42));
+///
+/// let err = strconv::atoi("4x2").unwrap_err();
+/// assert_eq!(err.to_string(), "strconv.atoi: parsing '4x2': invalid syntax");
+/// ```
+pub fn atoi(s: &str) -> Result<isize, NumError<'_>> {
+    const FN_ATOI: &str = "atoi";
+
+    // The longest input, sign included, whose digits always fit isize.
+    const FAST_LEN: usize = match usize::BITS {
+        16 => 4,
+        32 => 9,
+        _ => 18,
+    };
+
+    let s0 = s.as_bytes();
+    if (1..=FAST_LEN).contains(&s0.len()) {
+        // Fast path for small integers that fit isize.
+        let s = match s0[0] {
+            b'-' | b'+' => &s0[1..],
+            _ => s0,
+        };
+        if s.is_empty() {
+            return Err(NumError::syntax(FN_ATOI, s0));
+        }
+
+        let mut n: isize = 0;
+        for (i, &c) in s.iter().enumerate() {
+            let d = c.wrapping_sub(b'0');
+            if d > 9 {
+                let offset = s0.len() - s.len() + i;
+                return Err(NumError::syntax_at(FN_ATOI, s0, offset));
+            }
+            n = n * 10 + d as isize;
+        }
+
+        if s0[0] == b'-' {
+            n = -n;
+        }
+        return Ok(n);
+    }
+
+    // Slow path for invalid, big, or underscored integers.
+    parse_int(s, 10, 0)
+        .map(|v| v as isize)
+        .map_err(|err| NumError {
+            func: FN_ATOI,
+            ..err
+        })
+}
+
+/// Unsigned integer types backing the parsers and formatters, which are u64
+/// and u128.
+pub(crate) trait Unsigned:
+    Copy
+    + Ord
+    + From<u8>
+    + From<u64>
+    + Add<Output = Self>
+    + Div<Output = Self>
+    + Mul<Output = Self>
+    + Rem<Output = Self>
+    + Shl<u32, Output = Self>
+    + Shr<u32, Output = Self>
+    + Sub<Output = Self>
+{
+    /// The signed counterpart of the same width.
+    type Signed: Copy;
+
+    const BITS: u32;
+    const MAX: Self;
+    const ONE: Self;
+    const ZERO: Self;
+
+    /// Truncates self to usize, for values known to fit.
+    fn as_usize(self) -> usize;
+
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+
+    fn overflowing_add(self, rhs: Self) -> (Self, bool);
+
+    /// Casts self to the signed counterpart, negating it if `neg` is set.
+    /// The minimum signed value is mapped onto itself.
+    fn to_signed(self, neg: bool) -> Self::Signed;
+
+    fn range_signed(bound_hint: Self::Signed) -> NumErrorCause;
 
+    fn range_unsigned(bound_hint: Self) -> NumErrorCause;
+
+    /// Extracts the bound hint reported by [Unsigned::range_unsigned].
+    fn unsigned_bound_hint(cause: &NumErrorCause) -> Option<Self>;
+}
+
+macro_rules! impl_unsigned {
+    ($t:ty, $signed:ty, $range_signed:ident, $range_unsigned:ident) => {
+        impl Unsigned for $t {
+            type Signed = $signed;
+
+            const BITS: u32 = <$t>::BITS;
+            const MAX: Self = <$t>::MAX;
+            const ONE: Self = 1;
+            const ZERO: Self = 0;
+
+            fn as_usize(self) -> usize {
+                self as usize
+            }
+
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_mul(self, rhs)
+            }
+
+            fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+                <$t>::overflowing_add(self, rhs)
+            }
+
+            fn to_signed(self, neg: bool) -> Self::Signed {
+                let v = self as $signed;
+                if neg {
+                    v.wrapping_neg()
+                } else {
+                    v
+                }
+            }
+
+            fn range_signed(bound_hint: Self::Signed) -> NumErrorCause {
+                NumErrorCause::$range_signed { bound_hint }
+            }
+
+            fn range_unsigned(bound_hint: Self) -> NumErrorCause {
+                NumErrorCause::$range_unsigned { bound_hint }
+            }
+
+            fn unsigned_bound_hint(cause: &NumErrorCause) -> Option<Self> {
+                match cause {
+                    NumErrorCause::$range_unsigned { bound_hint } => Some(*bound_hint),
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+impl_unsigned!(u64, i64, OutOfRangeSigned, OutOfRangeUnsigned);
+impl_unsigned!(u128, i128, OutOfRangeSigned128, OutOfRangeUnsigned128);
+
+fn parse_signed<'a, U>(
+    func: &'static str,
+    s: &'a [u8],
+    base: u8,
+    bit_size: u8,
+    prefix: bool,
+) -> Result<(U::Signed, usize), NumError<'a>>
+where
+    U: Unsigned,
+{
     if s.is_empty() {
-        return Err(NumError::syntax(FN_PARSE_INT, s));
+        return Err(NumError::syntax(func, s));
     }
 
     let s0 = s;
-    let neg = s0.starts_with('-');
+    let neg = s0[0] == b'-';
     // Pick off leading sign.
-    let s = s.strip_prefix(|c| (c == '+') || (c == '-')).unwrap_or(s);
+    let s = if (s[0] == b'+') || (s[0] == b'-') {
+        &s[1..]
+    } else {
+        s
+    };
+    let sign_len = s0.len() - s.len();
 
     // Convert unsigned and check range.
-    let un = match parse_uint(s, base, bit_size) {
-        Err(mut err) => match err.err {
-            NumErrorCause::OutOfRangeUnsigned { bound_hint } => bound_hint,
-            _ => {
-                err.func = FN_PARSE_INT.to_string();
-                err.num = s0.to_string();
+    let (un, n) = match parse_unsigned::<U>(func, s, base, bit_size, prefix) {
+        Err(mut err) => match U::unsigned_bound_hint(&err.err) {
+            Some(bound_hint) => (bound_hint, 0),
+            None => {
+                err.num = num_str(s0);
+                if let Some(v) = err.invalid.as_mut() {
+                    v.offset += sign_len;
+                }
                 return Err(err);
             }
         },
@@ -60,176 +412,253 @@ pub fn parse_int(s: &'static str, base: u8, bit_size: u8) -> Result<i64, NumErro
         bit_size = usize::BITS;
     }
 
-    let cutoff = 1u64 << (bit_size - 1);
+    let cutoff = U::ONE << (bit_size - 1);
     if !neg && (un >= cutoff) {
-        let err = NumError::range_signed(FN_PARSE_INT, s0, (cutoff - 1) as i64);
-        return Err(err);
+        let hint = (cutoff - U::ONE).to_signed(false);
+        return Err(NumError::new(func, s0, U::range_signed(hint)));
     }
     if neg && (un > cutoff) {
-        let hint = if cutoff == (i64::MIN as u64) {
-            i64::MIN
-        } else {
-            -(cutoff as i64)
-        };
-        return Err(NumError::range_signed(FN_PARSE_INT, s0, hint));
+        let hint = cutoff.to_signed(true);
+        return Err(NumError::new(func, s0, U::range_signed(hint)));
     }
 
-    let mut n = un as i64;
-    if neg && (n != i64::MIN) {
-        n = -n;
-    }
-
-    Ok(n)
+    Ok((un.to_signed(neg), sign_len + n))
 }
 
-/// Like [parse_int] but for unsigned numbers.
+/// Parses the unsigned number at the start of s, returning the value and the
+/// count of bytes consumed.
 ///
-/// A sign prefix is not permitted.
-/// 
-/// # Example
-/// ```
-#[doc = include_str!("../examples/parse_uint.rs")]
-/// ```
-pub fn parse_uint(s: &'static str, base: u8, bit_size: u8) -> Result<u64, NumError> {
-    const FN_PARSE_UINT: &'static str = "parse_uint";
-
+/// If `prefix` is false, the whole s must be a number. Otherwise, parsing stops
+/// at the first byte which doesn't continue the number.
+fn parse_unsigned<'a, U>(
+    func: &'static str,
+    s: &'a [u8],
+    base: u8,
+    bit_size: u8,
+    prefix: bool,
+) -> Result<(U, usize), NumError<'a>>
+where
+    U: Unsigned,
+{
     if s.is_empty() {
-        return Err(NumError::syntax(FN_PARSE_UINT, s));
+        return Err(NumError::syntax(func, s));
     }
 
     let base0 = base == 0;
     let s0 = s;
+    let mut digits = false;
     let (s, base) = match base {
-        2..=36 => (s.as_bytes(), base), // valid base; nothing to do
+        2..=36 => (s, base), // valid base; nothing to do
         0 => {
             // Look for octal, hex prefix.
             let mut b = 10;
-            let mut s = s.as_bytes();
+            let mut s = s;
             if s[0] == b'0' {
-                if (s.len() >= 3) && (s[1].to_ascii_lowercase() == b'b') {
-                    b = 2;
-                    s = &s[2..];
-                } else if (s.len() >= 3) && (s[1].to_ascii_lowercase() == b'o') {
-                    b = 8;
-                    s = &s[2..];
-                } else if (s.len() >= 3) && (s[1].to_ascii_lowercase() == b'x') {
-                    b = 16;
+                let radix = match s.get(1).map(u8::to_ascii_lowercase) {
+                    Some(b'b') => 2,
+                    Some(b'o') => 8,
+                    Some(b'x') => 16,
+                    _ => 0,
+                };
+                // A base prefix must be followed by something, which has to
+                // continue the number when parsing a prefix of s.
+                let prefixed = match s.get(2) {
+                    Some(&c) if radix != 0 => !prefix || (c == b'_') || (digit(c) < radix),
+                    _ => false,
+                };
+
+                if prefixed {
+                    b = radix;
                     s = &s[2..];
                 } else {
                     b = 8;
                     s = &s[1..];
+                    digits = true;
                 }
             }
 
             (s, b)
         }
-        _ => return Err(NumError::base(FN_PARSE_UINT, s0, base)),
+        _ => return Err(NumError::base(func, s0, base)),
     };
 
     let bit_size = if bit_size == 0 {
-        usize::BITS as u8
-    } else if bit_size > 64 {
-        return Err(NumError::bit_size(FN_PARSE_UINT, s0, bit_size));
+        usize::BITS
+    } else if (bit_size as u32) > U::BITS {
+        return Err(NumError::bit_size(func, s0, bit_size));
     } else {
-        bit_size
+        bit_size as u32
     };
 
-    // Cutoff is the smallest number such that cutoff*base > u64::MAX.
+    // Cutoff is the smallest number such that cutoff*base > U::MAX.
     // Use compile-time constants for common cases.
     let cutoff = match base {
-        10 => u64::MAX / 10 + 1,
-        16 => u64::MAX / 16 + 1,
-        _ => u64::MAX / (base as u64) + 1,
+        10 => U::MAX / U::from(10u8) + U::ONE,
+        16 => U::MAX / U::from(16u8) + U::ONE,
+        _ => U::MAX / U::from(base) + U::ONE,
     };
 
-    let max_val = if bit_size == 64 {
-        u64::MAX
+    let max_val = if bit_size == U::BITS {
+        U::MAX
     } else {
-        (1u64 << (bit_size as usize)) - 1
+        (U::ONE << bit_size) - U::ONE
     };
 
     let mut underscores = false;
-    let mut n = 0u64;
-    for &c in s {
+    let mut n = U::ZERO;
+    let mut end = 0;
+    while let Some(&c) = s.get(end) {
+        // Take 8 digits at once where possible. Anything else, including any
+        // overflow, is left to the loop below, which reports the errors.
+        if let Some(n1) = accumulate8(n, &s[end..], base, max_val) {
+            n = n1;
+            digits = true;
+            end += 8;
+            continue;
+        }
+
         let d = match c {
             b'_' if base0 => {
                 underscores = true;
+                end += 1;
                 continue;
             }
-            b'0'..=b'9' => c - b'0',
-            b'a'..=b'z' => c - b'a' + 10,
-            b'A'..=b'Z' => c - b'A' + 10,
-            _ => return Err(NumError::syntax(FN_PARSE_UINT, s0)),
+            _ => digit(c),
         };
 
         if d >= base {
-            return Err(NumError::syntax(FN_PARSE_UINT, s0));
+            if prefix {
+                break;
+            }
+            // A misplaced underscore ahead of c takes precedence.
+            let mut offset = s0.len() - s.len() + end;
+            if underscores {
+                if let Err(v) = underscore_ok(&s0[..offset]) {
+                    offset = v;
+                }
+            }
+            return Err(NumError::syntax_at(func, s0, offset));
         }
 
         if n >= cutoff {
             // n*base overflows
-            return Err(NumError::range_unsigned(FN_PARSE_UINT, s0, max_val));
+            return Err(NumError::new(func, s0, U::range_unsigned(max_val)));
         }
-        n *= base as u64;
+        n = n * U::from(base);
 
-        let n1 = n.wrapping_add(d as u64);
-        if (n1 < n) || (n1 > max_val) {
+        let (n1, overflow) = n.overflowing_add(U::from(d));
+        if overflow || (n1 > max_val) {
             // n+d overflows
-            return Err(NumError::range_unsigned(FN_PARSE_UINT, s0, max_val));
+            return Err(NumError::new(func, s0, U::range_unsigned(max_val)));
         }
         n = n1;
+        digits = true;
+        end += 1;
     }
 
-    if underscores && !underscore_ok(s0) {
-        return Err(NumError::syntax(FN_PARSE_UINT, s0));
+    let consumed = s0.len() - s.len() + end;
+    if underscores {
+        if let Err(offset) = underscore_ok(&s0[..consumed]) {
+            return Err(NumError::syntax_at(func, s0, offset));
+        }
     }
 
-    Ok(n)
+    if !digits {
+        if consumed < s0.len() {
+            return Err(NumError::syntax_at(func, s0, consumed));
+        }
+        return Err(NumError::syntax(func, s0));
+    }
+
+    Ok((n, consumed))
 }
 
-fn underscore_ok(s: &str) -> bool {
+/// Returns n extended by the 8 digits at the start of s, if s starts with as
+/// many digits in base 10 or 16 and the result doesn't exceed max_val.
+///
+/// The digits are taken one by one otherwise, so this is a mere shortcut: the
+/// byte-wise loop would arrive at the same value without any error.
+fn accumulate8<U>(n: U, s: &[u8], base: u8, max_val: U) -> Option<U>
+where
+    U: Unsigned,
+{
+    let s = s.get(..8)?.try_into().ok()?;
+    let (v, scale) = match base {
+        10 => (swar::parse8_dec(s)?, 100_000_000u64),
+        16 => (swar::parse8_hex(s)?, 1u64 << 32),
+        _ => return None,
+    };
+
+    let (n1, overflow) = n.checked_mul(U::from(scale))?.overflowing_add(U::from(v));
+    if overflow || (n1 > max_val) {
+        return None;
+    }
+    Some(n1)
+}
+
+/// Returns the value of the digit c in base 36, or 36 if c isn't a digit.
+pub(crate) const fn digit(c: u8) -> u8 {
+    match c {
+        b'0'..=b'9' => c - b'0',
+        b'a'..=b'z' => c - b'a' + 10,
+        b'A'..=b'Z' => c - b'A' + 10,
+        _ => 36,
+    }
+}
+
+/// Reports whether the underscores in s are allowed, as defined by the Go
+/// syntax for integer literals. Otherwise, the error carries the offset of
+/// the first misplaced underscore.
+///
+/// It's a `const fn` shared with the const parsers.
+pub(crate) const fn underscore_ok(s: &[u8]) -> Result<(), usize> {
     let mut saw = b'^';
     let mut i = 0;
-    let mut s = s.as_bytes();
 
-    if (s.len() >= 1) && ((s[0] == b'-') || (s[0] == b'+')) {
-        s = &s[1..];
+    // Optional sign.
+    if !s.is_empty() && ((s[0] == b'-') || (s[0] == b'+')) {
+        i = 1;
     }
 
     let mut hex = false;
-    if (s.len() >= 2)
-        && (s[0] == b'0')
-        && ((s[1].to_ascii_lowercase() == b'b')
-            || (s[1].to_ascii_lowercase() == b'o')
-            || (s[1].to_ascii_lowercase() == b'x'))
+    if (s.len() >= i + 2)
+        && (s[i] == b'0')
+        && ((s[i + 1].eq_ignore_ascii_case(&b'b'))
+            || (s[i + 1].eq_ignore_ascii_case(&b'o'))
+            || (s[i + 1].eq_ignore_ascii_case(&b'x')))
     {
-        i = 2;
         saw = b'0';
-        hex = s[1].to_ascii_lowercase() == b'x';
+        hex = s[i + 1].eq_ignore_ascii_case(&b'x');
+        i += 2;
     }
 
-    for j in i..s.len() {
-        let c = s[j];
+    while i < s.len() {
+        let c = s[i];
+        i += 1;
 
-        if ((b'0' <= c) && (c <= b'9')) || (hex && (b'a' <= c) && (c <= b'f')) {
+        if c.is_ascii_digit() || (hex && matches!(c.to_ascii_lowercase(), b'a'..=b'f')) {
             saw = b'0';
             continue;
         }
 
         if c == b'_' {
             if saw != b'0' {
-                return false;
+                return Err(i - 1);
             }
             saw = b'_';
             continue;
         }
 
         if saw == b'_' {
-            return false;
+            return Err(i - 2);
         }
 
         saw = b'!';
     }
 
-    saw != b'_'
+    if saw == b'_' {
+        return Err(s.len() - 1);
+    }
+
+    Ok(())
 }
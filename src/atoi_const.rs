@@ -0,0 +1,225 @@
+use crate::atoi::{digit, underscore_ok};
+use crate::NumErrorCause;
+
+/// Like [parse_int][crate::parse_int] but usable in const contexts.
+///
+/// It accepts the same syntax, including the base prefixes and underscores for
+/// base 0. Since the input can't be recorded in const contexts, only the
+/// [NumErrorCause] is reported.
+///
+/// # Example
+/// ```
+/// const V: Result<i64, strconv::NumErrorCause> = strconv::parse_int_const("-0x_FF_FF", 0, 32);
+/// assert_eq!(V, Ok(-0xffff));
+/// ```
+pub const fn parse_int_const(s: &str, base: u8, bit_size: u8) -> Result<i64, NumErrorCause> {
+    let s = s.as_bytes();
+    if s.is_empty() {
+        return Err(NumErrorCause::InvalidSyntax);
+    }
+
+    let neg = s[0] == b'-';
+    // Pick off leading sign.
+    let start = if (s[0] == b'+') || (s[0] == b'-') {
+        1
+    } else {
+        0
+    };
+
+    // Convert unsigned and check range.
+    let un = match parse_unsigned_const(s, start, base, bit_size) {
+        Ok(v) => v,
+        Err(NumErrorCause::OutOfRangeUnsigned { bound_hint }) => bound_hint,
+        Err(err) => return Err(err),
+    };
+
+    let bit_size = if bit_size == 0 {
+        usize::BITS
+    } else {
+        bit_size as u32
+    };
+
+    let cutoff = 1u64 << (bit_size - 1);
+    if !neg && (un >= cutoff) {
+        let bound_hint = (cutoff - 1) as i64;
+        return Err(NumErrorCause::OutOfRangeSigned { bound_hint });
+    }
+    if neg && (un > cutoff) {
+        let bound_hint = (cutoff as i64).wrapping_neg();
+        return Err(NumErrorCause::OutOfRangeSigned { bound_hint });
+    }
+
+    let n = un as i64;
+    if neg {
+        Ok(n.wrapping_neg())
+    } else {
+        Ok(n)
+    }
+}
+
+/// Like [parse_uint][crate::parse_uint] but usable in const contexts.
+///
+/// See [parse_int_const] for how errors are reported.
+///
+/// # Example
+/// ```
+/// const V: Result<u64, strconv::NumErrorCause> = strconv::parse_uint_const("0o777", 0, 16);
+/// assert_eq!(V, Ok(0o777));
+/// ```
+pub const fn parse_uint_const(s: &str, base: u8, bit_size: u8) -> Result<u64, NumErrorCause> {
+    parse_unsigned_const(s.as_bytes(), 0, base, bit_size)
+}
+
+/// Like [parse_int_const] but panics on errors, which turns them into compile
+/// errors when evaluated for a `const` item.
+///
+/// # Example
+/// ```
+/// const MASK: i64 = strconv::must_parse_int("-0x_FF_FF", 0, 32);
+/// assert_eq!(MASK, -0xffff);
+/// ```
+///
+/// A misplaced underscore fails the build.
+/// ```compile_fail
+/// const MASK: i64 = strconv::must_parse_int("-0x_FF__FF", 0, 32);
+/// ```
+pub const fn must_parse_int(s: &str, base: u8, bit_size: u8) -> i64 {
+    match parse_int_const(s, base, bit_size) {
+        Ok(v) => v,
+        Err(err) => panic!("{}", panic_message(true, err)),
+    }
+}
+
+/// Like [parse_uint_const] but panics on errors, which turns them into compile
+/// errors when evaluated for a `const` item.
+///
+/// # Example
+/// ```
+/// const REG_BASE: u64 = strconv::must_parse_uint("0x4000_1000", 0, 32);
+/// assert_eq!(REG_BASE, 0x4000_1000);
+/// ```
+///
+/// A value out of range fails the build.
+/// ```compile_fail
+/// const REG_BASE: u64 = strconv::must_parse_uint("0x1_0000_0000", 0, 32);
+/// ```
+pub const fn must_parse_uint(s: &str, base: u8, bit_size: u8) -> u64 {
+    match parse_uint_const(s, base, bit_size) {
+        Ok(v) => v,
+        Err(err) => panic!("{}", panic_message(false, err)),
+    }
+}
+
+/// Parses the unsigned number in s[start..], where s may carry a sign ahead of
+/// start for checking underscores.
+const fn parse_unsigned_const(
+    s: &[u8],
+    start: usize,
+    base: u8,
+    bit_size: u8,
+) -> Result<u64, NumErrorCause> {
+    if start >= s.len() {
+        return Err(NumErrorCause::InvalidSyntax);
+    }
+
+    let base0 = base == 0;
+    let mut i = start;
+    let base = match base {
+        2..=36 => base, // valid base; nothing to do
+        0 => {
+            // Look for octal, hex prefix.
+            let mut b = 10;
+            if s[i] == b'0' {
+                let remaining = s.len() - i;
+                if (remaining >= 3) && s[i + 1].eq_ignore_ascii_case(&b'b') {
+                    b = 2;
+                    i += 2;
+                } else if (remaining >= 3) && s[i + 1].eq_ignore_ascii_case(&b'o') {
+                    b = 8;
+                    i += 2;
+                } else if (remaining >= 3) && s[i + 1].eq_ignore_ascii_case(&b'x') {
+                    b = 16;
+                    i += 2;
+                } else {
+                    b = 8;
+                    i += 1;
+                }
+            }
+
+            b
+        }
+        _ => return Err(NumErrorCause::InvalidBase(base)),
+    };
+
+    let bit_size = if bit_size == 0 {
+        usize::BITS
+    } else if bit_size > 64 {
+        return Err(NumErrorCause::InvalidBitSize(bit_size));
+    } else {
+        bit_size as u32
+    };
+
+    // Cutoff is the smallest number such that cutoff*base > u64::MAX.
+    let cutoff = u64::MAX / (base as u64) + 1;
+
+    let max_val = if bit_size == 64 {
+        u64::MAX
+    } else {
+        (1u64 << bit_size) - 1
+    };
+
+    let mut underscores = false;
+    let mut n = 0u64;
+    while i < s.len() {
+        let c = s[i];
+        i += 1;
+
+        if base0 && (c == b'_') {
+            underscores = true;
+            continue;
+        }
+
+        let d = digit(c);
+        if d >= base {
+            return Err(NumErrorCause::InvalidSyntax);
+        }
+
+        if n >= cutoff {
+            // n*base overflows
+            return Err(NumErrorCause::OutOfRangeUnsigned {
+                bound_hint: max_val,
+            });
+        }
+        n *= base as u64;
+
+        let n1 = n.wrapping_add(d as u64);
+        if (n1 < n) || (n1 > max_val) {
+            // n+d overflows
+            return Err(NumErrorCause::OutOfRangeUnsigned {
+                bound_hint: max_val,
+            });
+        }
+        n = n1;
+    }
+
+    if underscores && underscore_ok(s).is_err() {
+        return Err(NumErrorCause::InvalidSyntax);
+    }
+
+    Ok(n)
+}
+
+/// Picks the panic message for err, given the panic can't format values in
+/// const contexts.
+const fn panic_message(signed: bool, err: NumErrorCause) -> &'static str {
+    match (signed, err) {
+        (true, NumErrorCause::InvalidBase(_)) => "strconv.parse_int: invalid base",
+        (true, NumErrorCause::InvalidBitSize(_)) => "strconv.parse_int: invalid bit size",
+        (true, NumErrorCause::InvalidSyntax) => "strconv.parse_int: invalid syntax",
+        (true, _) => "strconv.parse_int: signed value out of range",
+        (false, NumErrorCause::InvalidBase(_)) => "strconv.parse_uint: invalid base",
+        (false, NumErrorCause::InvalidBitSize(_)) => "strconv.parse_uint: invalid bit size",
+        (false, NumErrorCause::InvalidSyntax) => "strconv.parse_uint: invalid syntax",
+        (false, _) => "strconv.parse_uint: unsigned value out of range",
+    }
+}
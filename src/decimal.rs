@@ -0,0 +1,451 @@
+//! Multiprecision decimal numbers, for the slow path of float parsing.
+
+/// The parameters of a binary floating-point format.
+pub(crate) struct FloatInfo {
+    pub(crate) mant_bits: u32,
+    pub(crate) exp_bits: u32,
+    pub(crate) bias: i32,
+}
+
+pub(crate) const FLOAT32_INFO: FloatInfo = FloatInfo {
+    mant_bits: 23,
+    exp_bits: 8,
+    bias: -127,
+};
+
+pub(crate) const FLOAT64_INFO: FloatInfo = FloatInfo {
+    mant_bits: 52,
+    exp_bits: 11,
+    bias: -1023,
+};
+
+/// The count of digits kept, beyond which nonzero digits are only recorded
+/// by the trunc flag.
+const MAX_DIGITS: usize = 800;
+
+/// The largest shift which can't overflow the u64 accumulators, given that
+/// they hold a digit shifted by it along with a carry.
+const MAX_SHIFT: u32 = 60;
+
+/// A decimal number of the form 0.d[..nd] * 10^dp.
+pub(crate) struct Decimal {
+    /// the digits, big-endian and in ASCII
+    d: [u8; MAX_DIGITS],
+    /// the count of digits used
+    nd: usize,
+    /// the position of the decimal point
+    dp: i32,
+    neg: bool,
+    /// whether nonzero digits beyond d[..nd] were discarded
+    trunc: bool,
+}
+
+impl Decimal {
+    /// Reads the decimal floating-point number in s, which has been checked
+    /// for the syntax, ignoring any underscores.
+    pub(crate) fn new(s: &[u8]) -> Self {
+        let mut b = Self {
+            d: [0; MAX_DIGITS],
+            nd: 0,
+            dp: 0,
+            neg: false,
+            trunc: false,
+        };
+
+        let mut i = 0;
+        match s.first() {
+            Some(b'+') => i += 1,
+            Some(b'-') => {
+                b.neg = true;
+                i += 1;
+            }
+            _ => {}
+        }
+
+        let mut saw_dot = false;
+        while let Some(&c) = s.get(i) {
+            match c {
+                b'_' => {}
+                b'.' => {
+                    saw_dot = true;
+                    b.dp = b.nd as i32;
+                }
+                b'0' if b.nd == 0 => {
+                    // Ignore leading zeros.
+                    b.dp -= 1;
+                }
+                b'0'..=b'9' => {
+                    if b.nd < MAX_DIGITS {
+                        b.d[b.nd] = c;
+                        b.nd += 1;
+                    } else if c != b'0' {
+                        b.trunc = true;
+                    }
+                }
+                _ => break,
+            }
+            i += 1;
+        }
+        if !saw_dot {
+            b.dp = b.nd as i32;
+        }
+
+        // The optional exponent moves the decimal point.
+        if s.get(i).map_or(false, |c| c.eq_ignore_ascii_case(&b'e')) {
+            i += 1;
+            let mut e_neg = false;
+            match s.get(i) {
+                Some(b'+') => i += 1,
+                Some(b'-') => {
+                    e_neg = true;
+                    i += 1;
+                }
+                _ => {}
+            }
+
+            let mut e = 0i32;
+            for &c in &s[i..] {
+                if c.is_ascii_digit() && (e < 10000) {
+                    e = e * 10 + (c - b'0') as i32;
+                }
+            }
+            b.dp += if e_neg { -e } else { e };
+        }
+
+        b
+    }
+
+    /// Returns the bits of the float nearest to the number, rounding half to
+    /// even, and whether it overflows to an infinity.
+    pub(crate) fn float_bits(&mut self, flt: &FloatInfo) -> (u64, bool) {
+        let (mant, exp, overflow) = match self.round_to_float(flt) {
+            Some((mant, exp)) => (mant, exp, false),
+            // ±Inf
+            None => (0, (1 << flt.exp_bits) - 1 + flt.bias, true),
+        };
+
+        // Assemble the bits.
+        let mut bits = mant & ((1 << flt.mant_bits) - 1);
+        bits |= (((exp - flt.bias) as u64) & ((1 << flt.exp_bits) - 1)) << flt.mant_bits;
+        if self.neg {
+            bits |= 1 << flt.mant_bits << flt.exp_bits;
+        }
+        (bits, overflow)
+    }
+
+    /// Returns the mantissa and the exponent of the float nearest to the
+    /// number, or `None` if it overflows.
+    fn round_to_float(&mut self, flt: &FloatInfo) -> Option<(u64, i32)> {
+        // The count of binary digits covering a power of 10, for scaling.
+        const POWTAB: [i32; 9] = [1, 3, 6, 9, 13, 16, 19, 23, 26];
+
+        // Zero is always a special case.
+        if self.nd == 0 {
+            return Some((0, flt.bias));
+        }
+
+        // Obvious overflow and underflow, for 64-bit floats at most.
+        if self.dp > 310 {
+            return None;
+        }
+        if self.dp < -330 {
+            return Some((0, flt.bias));
+        }
+
+        // Scale by powers of two until in the range [0.5, 1).
+        let mut exp = 0;
+        while self.dp > 0 {
+            let n = POWTAB.get(self.dp as usize).copied().unwrap_or(27);
+            self.shift(-n);
+            exp += n;
+        }
+        while (self.dp < 0) || ((self.dp == 0) && (self.d[0] < b'5')) {
+            let n = POWTAB.get(-self.dp as usize).copied().unwrap_or(27);
+            self.shift(n);
+            exp -= n;
+        }
+
+        // The range is [0.5, 1) but the floats are in [1, 2).
+        exp -= 1;
+
+        // The minimal exponent is bias+1. Move a smaller one up, and
+        // denormalize the digits accordingly.
+        if exp < flt.bias + 1 {
+            let n = flt.bias + 1 - exp;
+            self.shift(-n);
+            exp += n;
+        }
+
+        let max_exp = (1 << flt.exp_bits) - 1 + flt.bias;
+        if exp >= max_exp {
+            return None;
+        }
+
+        // Extract 1+mant_bits bits.
+        self.shift(1 + flt.mant_bits as i32);
+        let mut mant = self.rounded_integer();
+
+        // Rounding might have added a bit, so shift down.
+        if mant == 2 << flt.mant_bits {
+            mant >>= 1;
+            exp += 1;
+            if exp >= max_exp {
+                return None;
+            }
+        }
+
+        // Denormalized?
+        if mant & (1 << flt.mant_bits) == 0 {
+            exp = flt.bias;
+        }
+        Some((mant, exp))
+    }
+
+    /// Multiplies the number by 2^k, or divides it by 2^-k for a negative k.
+    fn shift(&mut self, k: i32) {
+        if self.nd == 0 {
+            return;
+        }
+
+        let mut k = k;
+        if k > 0 {
+            while k > MAX_SHIFT as i32 {
+                self.left_shift(MAX_SHIFT);
+                k -= MAX_SHIFT as i32;
+            }
+            self.left_shift(k as u32);
+        } else if k < 0 {
+            while k < -(MAX_SHIFT as i32) {
+                self.right_shift(MAX_SHIFT);
+                k += MAX_SHIFT as i32;
+            }
+            self.right_shift(-k as u32);
+        }
+    }
+
+    /// Divides the number by 2^k, for k <= MAX_SHIFT.
+    fn right_shift(&mut self, k: u32) {
+        let mut r = 0; // read index
+        let mut w = 0; // write index
+
+        // Pick up enough leading digits to cover the first shift.
+        let mut n = 0u64;
+        while n >> k == 0 {
+            if r >= self.nd {
+                if n == 0 {
+                    // The number is 0, which isn't shifted.
+                    self.nd = 0;
+                    return;
+                }
+                while n >> k == 0 {
+                    n *= 10;
+                    r += 1;
+                }
+                break;
+            }
+            n = n * 10 + (self.d[r] - b'0') as u64;
+            r += 1;
+        }
+        self.dp -= r as i32 - 1;
+
+        let mask = (1u64 << k) - 1;
+
+        // Pick up a digit, put down a digit.
+        while r < self.nd {
+            let c = (self.d[r] - b'0') as u64;
+            let dig = n >> k;
+            n &= mask;
+            self.d[w] = dig as u8 + b'0';
+            w += 1;
+            n = n * 10 + c;
+            r += 1;
+        }
+
+        // Put down the extra digits.
+        while n > 0 {
+            let dig = n >> k;
+            n &= mask;
+            if w < MAX_DIGITS {
+                self.d[w] = dig as u8 + b'0';
+                w += 1;
+            } else if dig > 0 {
+                self.trunc = true;
+            }
+            n *= 10;
+        }
+
+        self.nd = w;
+        self.trim();
+    }
+
+    /// Multiplies the number by 2^k, for k <= MAX_SHIFT.
+    fn left_shift(&mut self, k: u32) {
+        let (mut delta, cutoff) = LEFT_CHEATS[k as usize];
+        if prefix_is_less_than(&self.d[..self.nd], cutoff) {
+            delta -= 1;
+        }
+
+        let mut w = self.nd + delta; // write index
+
+        // Pick up a digit, put down a digit, from right to left.
+        let mut n = 0u64;
+        for r in (0..self.nd).rev() {
+            n += ((self.d[r] - b'0') as u64) << k;
+            w -= 1;
+            self.put_digit(w, n % 10);
+            n /= 10;
+        }
+
+        // Put down the extra digits.
+        while n > 0 {
+            w -= 1;
+            self.put_digit(w, n % 10);
+            n /= 10;
+        }
+
+        self.nd = MAX_DIGITS.min(self.nd + delta);
+        self.dp += delta as i32;
+        self.trim();
+    }
+
+    /// Stores the digit d at index w, or records it by the trunc flag if it
+    /// doesn't fit.
+    fn put_digit(&mut self, w: usize, d: u64) {
+        if w < MAX_DIGITS {
+            self.d[w] = d as u8 + b'0';
+        } else if d != 0 {
+            self.trunc = true;
+        }
+    }
+
+    /// Drops the trailing zeros.
+    fn trim(&mut self) {
+        while (self.nd > 0) && (self.d[self.nd - 1] == b'0') {
+            self.nd -= 1;
+        }
+        if self.nd == 0 {
+            self.dp = 0;
+        }
+    }
+
+    /// Reports whether the number would be rounded up if truncated to nd
+    /// digits, rounding half to even.
+    fn should_round_up(&self, nd: usize) -> bool {
+        if nd >= self.nd {
+            return false;
+        }
+
+        if (self.d[nd] == b'5') && (nd + 1 == self.nd) {
+            // Exactly halfway, unless digits were truncated, which makes it
+            // a little higher.
+            if self.trunc {
+                return true;
+            }
+            return (nd > 0) && ((self.d[nd - 1] - b'0') % 2 != 0);
+        }
+
+        // Not halfway, so the digit tells all.
+        self.d[nd] >= b'5'
+    }
+
+    /// Returns the integer part of the number, rounded half to even, which
+    /// saturates at u64::MAX.
+    fn rounded_integer(&self) -> u64 {
+        if self.dp > 20 {
+            return u64::MAX;
+        }
+        if self.dp < 0 {
+            // Below 0.1, which rounds to 0.
+            return 0;
+        }
+
+        let dp = self.dp as usize;
+        let mut n = 0u64;
+        for i in 0..dp {
+            let d = if i < self.nd { self.d[i] - b'0' } else { 0 };
+            n = n.wrapping_mul(10).wrapping_add(d as u64);
+        }
+        if self.should_round_up(dp) {
+            n = n.wrapping_add(1);
+        }
+        n
+    }
+}
+
+/// Reports whether the digits in b are below the digits in s.
+fn prefix_is_less_than(b: &[u8], s: &[u8]) -> bool {
+    for (i, &c) in s.iter().enumerate() {
+        match b.get(i) {
+            None => return true,
+            Some(&d) if d != c => return d < c,
+            _ => {}
+        }
+    }
+    false
+}
+
+/// The count of new digits when shifting left by the index k, which is one
+/// less if the digits are below 5^k.
+const LEFT_CHEATS: [(usize, &[u8]); MAX_SHIFT as usize + 1] = [
+    (0, b""),
+    (1, b"5"),                                           // * 2
+    (1, b"25"),                                          // * 4
+    (1, b"125"),                                         // * 8
+    (2, b"625"),                                         // * 16
+    (2, b"3125"),                                        // * 32
+    (2, b"15625"),                                       // * 64
+    (3, b"78125"),                                       // * 128
+    (3, b"390625"),                                      // * 256
+    (3, b"1953125"),                                     // * 512
+    (4, b"9765625"),                                     // * 1024
+    (4, b"48828125"),                                    // * 2048
+    (4, b"244140625"),                                   // * 4096
+    (4, b"1220703125"),                                  // * 8192
+    (5, b"6103515625"),                                  // * 16384
+    (5, b"30517578125"),                                 // * 32768
+    (5, b"152587890625"),                                // * 65536
+    (6, b"762939453125"),                                // * 131072
+    (6, b"3814697265625"),                               // * 262144
+    (6, b"19073486328125"),                              // * 524288
+    (7, b"95367431640625"),                              // * 1048576
+    (7, b"476837158203125"),                             // * 2097152
+    (7, b"2384185791015625"),                            // * 4194304
+    (7, b"11920928955078125"),                           // * 8388608
+    (8, b"59604644775390625"),                           // * 16777216
+    (8, b"298023223876953125"),                          // * 33554432
+    (8, b"1490116119384765625"),                         // * 67108864
+    (9, b"7450580596923828125"),                         // * 134217728
+    (9, b"37252902984619140625"),                        // * 268435456
+    (9, b"186264514923095703125"),                       // * 536870912
+    (10, b"931322574615478515625"),                      // * 1073741824
+    (10, b"4656612873077392578125"),                     // * 2147483648
+    (10, b"23283064365386962890625"),                    // * 4294967296
+    (10, b"116415321826934814453125"),                   // * 8589934592
+    (11, b"582076609134674072265625"),                   // * 17179869184
+    (11, b"2910383045673370361328125"),                  // * 34359738368
+    (11, b"14551915228366851806640625"),                 // * 68719476736
+    (12, b"72759576141834259033203125"),                 // * 137438953472
+    (12, b"363797880709171295166015625"),                // * 274877906944
+    (12, b"1818989403545856475830078125"),               // * 549755813888
+    (13, b"9094947017729282379150390625"),               // * 1099511627776
+    (13, b"45474735088646411895751953125"),              // * 2199023255552
+    (13, b"227373675443232059478759765625"),             // * 4398046511104
+    (13, b"1136868377216160297393798828125"),            // * 8796093022208
+    (14, b"5684341886080801486968994140625"),            // * 17592186044416
+    (14, b"28421709430404007434844970703125"),           // * 35184372088832
+    (14, b"142108547152020037174224853515625"),          // * 70368744177664
+    (15, b"710542735760100185871124267578125"),          // * 140737488355328
+    (15, b"3552713678800500929355621337890625"),         // * 281474976710656
+    (15, b"17763568394002504646778106689453125"),        // * 562949953421312
+    (16, b"88817841970012523233890533447265625"),        // * 1125899906842624
+    (16, b"444089209850062616169452667236328125"),       // * 2251799813685248
+    (16, b"2220446049250313080847263336181640625"),      // * 4503599627370496
+    (16, b"11102230246251565404236316680908203125"),     // * 9007199254740992
+    (17, b"55511151231257827021181583404541015625"),     // * 18014398509481984
+    (17, b"277555756156289135105907917022705078125"),    // * 36028797018963968
+    (17, b"1387778780781445675529539585113525390625"),   // * 72057594037927936
+    (18, b"6938893903907228377647697925567626953125"),   // * 144115188075855872
+    (18, b"34694469519536141888238489627838134765625"),  // * 288230376151711744
+    (18, b"173472347597680709441192448139190673828125"), // * 576460752303423488
+    (19, b"867361737988403547205962240695953369140625"), // * 1152921504606846976
+];
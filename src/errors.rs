@@ -1,93 +1,284 @@
-use std::fmt::Debug;
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, string::String};
+use core::fmt::{self, Debug, Display, Formatter};
+
+use crate::ParseRadix;
 
 /// Records a failed conversion.
-#[derive(thiserror::Error, Debug, PartialEq, Eq)]
-#[error("strconv.{func}: parsing '{num}': {err}")]
-pub struct NumError {
+///
+/// It displays as `strconv.{func}: parsing '{num}': {err}`. With the alternate
+/// flag (`{:#}`), the location of the invalid character is appended if known,
+/// as in `strconv.parse_int: parsing '12x': invalid syntax at byte 2 ('x')`.
+///
+/// The input is borrowed as `num`, which avoids any allocation for inputs of
+/// valid UTF-8. Use `NumError::into_owned` to get an error outliving the input.
+#[derive(Debug, PartialEq, Eq)]
+pub struct NumError<'a> {
     /// the failing function (parse_int, parse_uint)
-    pub func: String,
+    pub func: &'static str,
     /// the input
-    pub num: String,
+    pub num: NumStr<'a>,
     /// the reason the conversion failed
-    #[source]
     pub err: NumErrorCause,
+    /// the first invalid digit or misplaced underscore, for syntax errors
+    /// pointing at a specific character
+    pub invalid: Option<InvalidChar>,
+}
+
+/// The input recorded by [NumError].
+///
+/// It's a `Cow<'a, str>` rendering invalid UTF-8 lossily, unless the `alloc`
+/// feature is disabled. In that case, it's the longest valid UTF-8 prefix of
+/// the input.
+#[cfg(feature = "alloc")]
+pub type NumStr<'a> = Cow<'a, str>;
+
+/// The input recorded by [NumError].
+///
+/// It's a `Cow<'a, str>` rendering invalid UTF-8 lossily, unless the `alloc`
+/// feature is disabled. In that case, it's the longest valid UTF-8 prefix of
+/// the input.
+#[cfg(not(feature = "alloc"))]
+pub type NumStr<'a> = &'a str;
+
+/// Locates the character which makes the input invalid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidChar {
+    /// the byte offset into the input
+    pub offset: usize,
+    /// the character at the offset, which is
+    /// [char::REPLACEMENT_CHARACTER] for bytes not being valid UTF-8
+    pub ch: char,
 }
 
 /// Reason of conversion failed.
-#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq)]
 pub enum NumErrorCause {
-    #[error("invalid base {0}")]
     InvalidBase(u8),
-    #[error("invalid bit size {0}")]
     InvalidBitSize(u8),
-    #[error("invalid syntax")]
     InvalidSyntax,
     /// Indicates that a signed value is out of range for the target type.
-    #[error("signed value out of range: {bound_hint}")]
-    OutOfRangeSigned { bound_hint: i64 },
+    OutOfRangeSigned {
+        bound_hint: i64,
+    },
     /// Indicates that a unsigned value is out of range for the target type.
-    #[error("unsigned value out of range: {bound_hint}")]
-    OutOfRangeUnsigned { bound_hint: u64 },
+    OutOfRangeUnsigned {
+        bound_hint: u64,
+    },
+    /// Like [NumErrorCause::OutOfRangeSigned] but for 128-bit parsing.
+    OutOfRangeSigned128 {
+        bound_hint: i128,
+    },
+    /// Like [NumErrorCause::OutOfRangeUnsigned] but for 128-bit parsing.
+    OutOfRangeUnsigned128 {
+        bound_hint: u128,
+    },
+    /// Indicates that a float is out of range for the target type, with the
+    /// infinity of its sign as hint, as read by
+    /// [bound_hint][NumErrorCause::bound_hint]`::<f64>()`.
+    OutOfRangeFloat {
+        negative: bool,
+    },
 }
 
-impl NumError {
-    pub(crate) fn base<S, T>(func: S, s: T, b: u8) -> Self
-    where
-        S: ToString,
-        T: ToString,
-    {
-        Self {
-            func: func.to_string(),
-            num: s.to_string(),
-            err: NumErrorCause::InvalidBase(b),
+#[cfg(feature = "std")]
+impl std::error::Error for NumError<'_> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.err)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NumErrorCause {}
+
+impl Display for NumError<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "strconv.{}: parsing '{}': {}",
+            self.func, self.num, self.err
+        )?;
+
+        match self.invalid {
+            Some(InvalidChar { offset, ch }) if f.alternate() => {
+                write!(f, " at byte {} ({:?})", offset, ch)
+            }
+            _ => Ok(()),
         }
     }
+}
 
-    pub(crate) fn bit_size<S, T>(func: S, s: T, bit_size: u8) -> Self
-    where
-        S: ToString,
-        T: ToString,
-    {
-        Self {
-            func: func.to_string(),
-            num: s.to_string(),
-            err: NumErrorCause::InvalidBitSize(bit_size),
+impl Display for NumErrorCause {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidBase(b) => write!(f, "invalid base {}", b),
+            Self::InvalidBitSize(v) => write!(f, "invalid bit size {}", v),
+            Self::InvalidSyntax => f.write_str("invalid syntax"),
+            Self::OutOfRangeSigned { bound_hint } => {
+                write!(f, "signed value out of range: {}", bound_hint)
+            }
+            Self::OutOfRangeUnsigned { bound_hint } => {
+                write!(f, "unsigned value out of range: {}", bound_hint)
+            }
+            Self::OutOfRangeSigned128 { bound_hint } => {
+                write!(f, "signed value out of range: {}", bound_hint)
+            }
+            Self::OutOfRangeUnsigned128 { bound_hint } => {
+                write!(f, "unsigned value out of range: {}", bound_hint)
+            }
+            Self::OutOfRangeFloat { negative } => {
+                let bound_hint = if *negative {
+                    f64::NEG_INFINITY
+                } else {
+                    f64::INFINITY
+                };
+                write!(f, "value out of range: {}", bound_hint)
+            }
         }
     }
+}
+
+impl InvalidChar {
+    /// Locates the character starting at s[offset].
+    pub(crate) fn at(s: &[u8], offset: usize) -> Self {
+        let end = s.len().min(offset + 4);
+        let ch = match core::str::from_utf8(&s[offset..end]) {
+            Ok(v) => v.chars().next(),
+            Err(err) => core::str::from_utf8(&s[offset..offset + err.valid_up_to()])
+                .ok()
+                .and_then(|v| v.chars().next()),
+        };
 
-    pub(crate) fn range_signed<S, T>(func: S, s: T, bound_hint: i64) -> Self
-    where
-        S: ToString,
-        T: ToString,
-    {
         Self {
-            func: func.to_string(),
-            num: s.to_string(),
-            err: NumErrorCause::OutOfRangeSigned { bound_hint },
+            offset,
+            ch: ch.unwrap_or(char::REPLACEMENT_CHARACTER),
         }
     }
+}
 
-    pub(crate) fn range_unsigned<S, T>(func: S, s: T, bound_hint: u64) -> Self
+impl NumErrorCause {
+    /// Returns the bound hint of an out-of-range error as type `T`, or `None`
+    /// if this isn't a range error matching the signedness of `T`, or a float
+    /// range error for float `T`.
+    ///
+    /// # Example
+    /// ```
+    /// let err = strconv::parse_float("-1e39", 32).unwrap_err();
+    /// assert_eq!(err.err.bound_hint(), Some(f64::NEG_INFINITY));
+    /// assert_eq!(err.err.bound_hint::<i64>(), None);
+    /// ```
+    pub fn bound_hint<T>(&self) -> Option<T>
     where
-        S: ToString,
-        T: ToString,
+        T: BoundHint,
     {
+        T::read_bound_hint(self)
+    }
+}
+
+/// Types the bound hint of an out-of-range error can be read as by
+/// [NumErrorCause::bound_hint], which are the [ParseRadix] integers and the
+/// floats.
+pub trait BoundHint: Sized {
+    /// Extracts the bound hint of an out-of-range error as the implementing
+    /// type, or `None` if cause isn't a range error of the kind of the type.
+    fn read_bound_hint(cause: &NumErrorCause) -> Option<Self>;
+}
+
+impl<T> BoundHint for T
+where
+    T: ParseRadix,
+{
+    fn read_bound_hint(cause: &NumErrorCause) -> Option<Self> {
+        T::from_bound_hint(cause)
+    }
+}
+
+macro_rules! impl_bound_hint_float {
+    ($($t:ty),*) => {
+        $(
+            impl BoundHint for $t {
+                fn read_bound_hint(cause: &NumErrorCause) -> Option<Self> {
+                    match cause {
+                        NumErrorCause::OutOfRangeFloat { negative: true } => {
+                            Some(<$t>::NEG_INFINITY)
+                        }
+                        NumErrorCause::OutOfRangeFloat { negative: false } => Some(<$t>::INFINITY),
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_bound_hint_float!(f32, f64);
+
+impl<'a> NumError<'a> {
+    /// Detaches the error from the input by copying `num` if borrowed, so
+    /// that it can outlive the input, e.g. to be boxed as
+    /// `Box<dyn std::error::Error>`.
+    ///
+    /// # Example
+    /// ```
+    /// let err = {
+    ///     let s = String::from("256");
+    ///     strconv::parse_uint(&s, 10, 8).unwrap_err().into_owned()
+    /// };
+    ///
+    /// assert_eq!(err.to_string(), "strconv.parse_uint: parsing '256': unsigned value out of range: 255");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn into_owned(self) -> NumError<'static> {
+        NumError {
+            func: self.func,
+            num: Cow::Owned(self.num.into_owned()),
+            err: self.err,
+            invalid: self.invalid,
+        }
+    }
+
+    pub(crate) fn new(func: &'static str, s: &'a [u8], err: NumErrorCause) -> Self {
         Self {
-            func: func.to_string(),
-            num: s.to_string(),
-            err: NumErrorCause::OutOfRangeUnsigned { bound_hint },
+            func,
+            num: num_str(s),
+            err,
+            invalid: None,
         }
     }
 
-    pub(crate) fn syntax<S, T>(func: S, s: T) -> Self
-    where
-        S: ToString,
-        T: ToString,
-    {
+    pub(crate) fn base(func: &'static str, s: &'a [u8], b: u8) -> Self {
+        Self::new(func, s, NumErrorCause::InvalidBase(b))
+    }
+
+    pub(crate) fn bit_size(func: &'static str, s: &'a [u8], bit_size: u8) -> Self {
+        Self::new(func, s, NumErrorCause::InvalidBitSize(bit_size))
+    }
+
+    pub(crate) fn syntax(func: &'static str, s: &'a [u8]) -> Self {
+        Self::new(func, s, NumErrorCause::InvalidSyntax)
+    }
+
+    /// Like [NumError::syntax] but locates the invalid character at s[offset].
+    pub(crate) fn syntax_at(func: &'static str, s: &'a [u8], offset: usize) -> Self {
         Self {
-            func: func.to_string(),
-            num: s.to_string(),
-            err: NumErrorCause::InvalidSyntax,
+            invalid: Some(InvalidChar::at(s, offset)),
+            ..Self::syntax(func, s)
         }
     }
 }
+
+/// Renders the input s as [NumStr].
+#[cfg(feature = "alloc")]
+pub(crate) fn num_str(s: &[u8]) -> NumStr<'_> {
+    String::from_utf8_lossy(s)
+}
+
+/// Renders the input s as [NumStr].
+#[cfg(not(feature = "alloc"))]
+pub(crate) fn num_str(s: &[u8]) -> NumStr<'_> {
+    match core::str::from_utf8(s) {
+        Ok(v) => v,
+        // The prefix up to the error is valid UTF-8.
+        Err(err) => core::str::from_utf8(&s[..err.valid_up_to()]).unwrap_or_default(),
+    }
+}
@@ -0,0 +1,95 @@
+use crate::itoa::{ascii_str, format_bits, BUF_LEN};
+
+/// A buffer on the stack for formatting integers of any primitive type
+/// without allocating.
+///
+/// It holds the longest representation possible, which is an `i128` or `u128`
+/// in base 2 along with the sign. The formatted string borrows the buffer, so
+/// it stays valid until the buffer is reused.
+///
+/// # Example
+/// ```
+/// let mut buf = strconv::FormatBuffer::new();
+///
+/// let s = buf.format(-42i8, 16);
+/// assert_eq!(s, "-2a");
+/// assert_eq!(strconv::parse_int(s, 16, 8), Ok(-42));
+///
+/// assert_eq!(buf.format(u128::MAX, 36), "f5lxx1zz5pnorynqglhzmsp33");
+/// ```
+#[derive(Clone, Copy)]
+pub struct FormatBuffer {
+    buf: [u8; BUF_LEN],
+}
+
+/// Integer types which can be formatted by [FormatBuffer].
+pub trait FormatRadix: Copy {
+    /// Renders self in the given base (2 to 36) into buf, and returns the
+    /// rendering.
+    ///
+    /// It panics if base is out of range, as `format_int`
+    /// does.
+    fn format_radix(self, buf: &mut FormatBuffer, base: u8) -> &str;
+}
+
+impl FormatBuffer {
+    /// Returns an empty buffer.
+    pub const fn new() -> Self {
+        Self { buf: [0; BUF_LEN] }
+    }
+
+    /// Returns the string representation of v in the given base,
+    /// for 2 <= base <= 36. The result uses the lower-case letters 'a' to 'z'
+    /// for digit values >= 10, as `format_int` does.
+    ///
+    /// # Panics
+    /// It panics if base is out of range, as `format_int`
+    /// does.
+    pub fn format<T>(&mut self, v: T, base: u8) -> &str
+    where
+        T: FormatRadix,
+    {
+        v.format_radix(self, base)
+    }
+}
+
+impl Default for FormatBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+const FN_FORMAT_BUFFER: &str = "FormatBuffer::format";
+
+macro_rules! impl_format_radix_signed {
+    ($u:ty: $($t:ty),*) => {
+        $(
+            impl FormatRadix for $t {
+                fn format_radix(self, buf: &mut FormatBuffer, base: u8) -> &str {
+                    let u = self.unsigned_abs() as $u;
+                    let start = format_bits(FN_FORMAT_BUFFER, &mut buf.buf, u, base, self < 0);
+                    ascii_str(&buf.buf[start..])
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_format_radix_unsigned {
+    ($u:ty: $($t:ty),*) => {
+        $(
+            impl FormatRadix for $t {
+                fn format_radix(self, buf: &mut FormatBuffer, base: u8) -> &str {
+                    let start = format_bits(FN_FORMAT_BUFFER, &mut buf.buf, self as $u, base, false);
+                    ascii_str(&buf.buf[start..])
+                }
+            }
+        )*
+    };
+}
+
+impl_format_radix_signed!(u64: i8, i16, i32, i64, isize);
+impl_format_radix_signed!(u128: i128);
+
+impl_format_radix_unsigned!(u64: u8, u16, u32, u64, usize);
+impl_format_radix_unsigned!(u128: u128);
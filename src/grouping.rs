@@ -0,0 +1,393 @@
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+use crate::itoa::count_digits;
+#[cfg(feature = "alloc")]
+use crate::itoa::{format_bits, BUF_LEN};
+use crate::{NumError, NumErrorCause};
+
+/// How digits are grouped, which is by a separator between groups of a fixed
+/// size, counting from the last digit.
+///
+/// The lowest group may differ in size from the others, as in the Indian
+/// "12,34,567".
+///
+/// # Example
+/// ```
+/// use strconv::Grouping;
+///
+/// assert_eq!(strconv::parse_int_grouped("-1,234,567", Grouping::COMMA, 64), Ok(-1234567));
+/// assert_eq!(strconv::parse_uint_grouped("12,34,567", Grouping::INDIAN, 64), Ok(1234567));
+///
+/// let err = strconv::parse_int_grouped("1,23,456", Grouping::COMMA, 64).unwrap_err();
+/// assert_eq!(err.err, strconv::NumErrorCause::InvalidSyntax);
+/// assert_eq!(err.invalid.map(|v| v.offset), Some(4));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Grouping {
+    separator: char,
+    first: u8,
+    rest: u8,
+}
+
+/// The conventions for writing numbers in a locale, as looked up by
+/// [Locale::lookup].
+///
+/// The parsers of the crate take integers only, so a number with fraction
+/// digits is split at the decimal separator first.
+///
+/// # Example
+/// ```
+/// let de = strconv::Locale::lookup("de").unwrap();
+/// let (int, frac) = "1.234,5".split_once(de.decimal).unwrap();
+///
+/// assert_eq!(strconv::parse_int_grouped(int, de.grouping, 64), Ok(1234));
+/// assert_eq!(strconv::parse_uint(frac, 10, 64), Ok(5));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Locale {
+    /// the grouping of integer digits
+    pub grouping: Grouping,
+    /// the character ahead of the fraction digits
+    pub decimal: char,
+}
+
+impl Grouping {
+    /// Groups of 3 separated by ',', as in "1,234,567".
+    pub const COMMA: Self = Self::new(',', 3, 3);
+    /// Groups of 3 separated by '.', as in "1.234.567".
+    pub const PERIOD: Self = Self::new('.', 3, 3);
+    /// Groups of 3 separated by a no-break space (U+00A0), as in "1 234 567".
+    pub const NBSP: Self = Self::new('\u{a0}', 3, 3);
+    /// Groups of 3 separated by a narrow no-break space (U+202F), as in
+    /// "1 234 567".
+    pub const NARROW_NBSP: Self = Self::new('\u{202f}', 3, 3);
+    /// Groups of 3 separated by a right single quotation mark (U+2019), as in
+    /// "1’234’567".
+    pub const APOSTROPHE: Self = Self::new('\u{2019}', 3, 3);
+    /// A group of 3 followed by groups of 2, separated by ',', as in
+    /// "12,34,567".
+    pub const INDIAN: Self = Self::new(',', 3, 2);
+
+    /// Returns the grouping with the given separator, the size of the lowest
+    /// group and the size of the others.
+    ///
+    /// # Panics
+    /// It panics if a size is 0 or the separator is an ASCII digit or sign.
+    pub const fn new(separator: char, first: u8, rest: u8) -> Self {
+        assert!(
+            (first > 0) && (rest > 0),
+            "strconv.Grouping::new: groups must not be empty"
+        );
+        assert!(
+            !matches!(separator, '0'..='9' | '+' | '-'),
+            "strconv.Grouping::new: separator can't be a digit or sign"
+        );
+
+        Self {
+            separator,
+            first,
+            rest,
+        }
+    }
+
+    /// Returns the separator between groups.
+    pub const fn separator(&self) -> char {
+        self.separator
+    }
+
+    /// Returns the length in bytes of
+    /// `format_int_grouped(i, self)` without
+    /// formatting it.
+    ///
+    /// # Example
+    /// ```
+    /// use strconv::Grouping;
+    ///
+    /// assert_eq!(Grouping::COMMA.formatted_len(-1234567), 10);
+    /// assert_eq!(Grouping::NBSP.formatted_len(1234567), 11);
+    /// ```
+    pub fn formatted_len(self, i: i64) -> usize {
+        usize::from(i < 0) + self.len(i.unsigned_abs())
+    }
+
+    /// Returns the length in bytes of
+    /// `format_uint_grouped(u, self)` without
+    /// formatting it.
+    pub fn formatted_uint_len(self, u: u64) -> usize {
+        self.len(u)
+    }
+
+    fn len(self, u: u64) -> usize {
+        let digits = count_digits(u.into(), 10);
+        let (first, rest) = (self.first as usize, self.rest as usize);
+        let separators = match digits.checked_sub(first + 1) {
+            Some(n) => n / rest + 1,
+            None => 0,
+        };
+        digits + separators * self.separator.len_utf8()
+    }
+}
+
+/// Known locales as (language tag, grouping, decimal separator), where a tag
+/// is matched before its language alone.
+const LOCALES: &[(&str, Grouping, char)] = &[
+    ("en", Grouping::COMMA, '.'),
+    ("en-in", Grouping::INDIAN, '.'),
+    ("en-za", Grouping::NBSP, ','),
+    ("hi", Grouping::INDIAN, '.'),
+    ("ja", Grouping::COMMA, '.'),
+    ("ko", Grouping::COMMA, '.'),
+    ("zh", Grouping::COMMA, '.'),
+    ("th", Grouping::COMMA, '.'),
+    ("he", Grouping::COMMA, '.'),
+    ("de", Grouping::PERIOD, ','),
+    ("de-ch", Grouping::APOSTROPHE, '.'),
+    ("es", Grouping::PERIOD, ','),
+    ("es-mx", Grouping::COMMA, '.'),
+    ("it", Grouping::PERIOD, ','),
+    ("nl", Grouping::PERIOD, ','),
+    ("pt", Grouping::PERIOD, ','),
+    ("pt-pt", Grouping::NBSP, ','),
+    ("da", Grouping::PERIOD, ','),
+    ("el", Grouping::PERIOD, ','),
+    ("id", Grouping::PERIOD, ','),
+    ("tr", Grouping::PERIOD, ','),
+    ("fr", Grouping::NARROW_NBSP, ','),
+    ("fr-ch", Grouping::NARROW_NBSP, '.'),
+    ("cs", Grouping::NBSP, ','),
+    ("fi", Grouping::NBSP, ','),
+    ("hu", Grouping::NBSP, ','),
+    ("nb", Grouping::NBSP, ','),
+    ("pl", Grouping::NBSP, ','),
+    ("ru", Grouping::NBSP, ','),
+    ("sk", Grouping::NBSP, ','),
+    ("sv", Grouping::NBSP, ','),
+    ("uk", Grouping::NBSP, ','),
+];
+
+impl Locale {
+    /// Looks up the conventions of the locale named by a language tag such as
+    /// "de" or "en-IN", from a table built into the crate. No locale data of
+    /// the operating system is used.
+    ///
+    /// Tags are matched regardless of case, with '-' or '_' between subtags.
+    /// Unknown regions fall back to their language, and `None` is returned
+    /// for unknown languages.
+    ///
+    /// # Example
+    /// ```
+    /// use strconv::{Grouping, Locale};
+    ///
+    /// let de = Locale::lookup("de_AT").unwrap();
+    /// assert_eq!(de.grouping, Grouping::PERIOD);
+    /// assert_eq!(de.decimal, ',');
+    ///
+    /// assert_eq!(Locale::lookup("en-IN").map(|v| v.grouping), Some(Grouping::INDIAN));
+    /// assert_eq!(Locale::lookup("tlh"), None);
+    /// ```
+    pub fn lookup(tag: &str) -> Option<Self> {
+        let mut tag = tag;
+        loop {
+            let found = LOCALES.iter().find(|(v, _, _)| tag_eq(v, tag));
+            if let Some(&(_, grouping, decimal)) = found {
+                return Some(Self { grouping, decimal });
+            }
+
+            // Drop the last subtag.
+            tag = &tag[..tag.rfind(['-', '_'])?];
+        }
+    }
+}
+
+/// Reports whether the tags a and b are the same, where a is in lower case
+/// with '-' between subtags.
+fn tag_eq(a: &str, b: &str) -> bool {
+    (a.len() == b.len())
+        && a.bytes().zip(b.bytes()).all(|(x, y)| match y {
+            b'_' => x == b'-',
+            _ => x == y.to_ascii_lowercase(),
+        })
+}
+
+/// Interprets a decimal string s whose digits are grouped as given, and
+/// returns the value fitting into `bit_size` bits (0 to 64) as
+/// [parse_int][crate::parse_int] does.
+///
+/// The string may begin with a sign. Digits may come without any separator
+/// at all, but once there is one, all groups must be complete: the leftmost
+/// group has 1 up to as many digits as the others, the lowest group has
+/// exactly its size, and separators never lead, trail or double. Otherwise,
+/// `err.err` = [NumErrorCause::InvalidSyntax][crate::NumErrorCause::InvalidSyntax]
+/// with `err.invalid` locating the misplaced separator, as for misplaced
+/// underscores in base 0.
+///
+/// # Example
+/// ```
+/// use strconv::{Grouping, Locale};
+///
+/// let fr = Locale::lookup("fr").unwrap().grouping;
+/// assert_eq!(strconv::parse_int_grouped("-1\u{202f}234", fr, 32), Ok(-1234));
+/// assert_eq!(strconv::parse_int_grouped("1234", fr, 32), Ok(1234));
+///
+/// let err = strconv::parse_int_grouped("1.234.56", Grouping::PERIOD, 64).unwrap_err();
+/// assert_eq!(err.invalid.map(|v| v.offset), Some(5));
+/// ```
+pub fn parse_int_grouped(s: &str, grouping: Grouping, bit_size: u8) -> Result<i64, NumError<'_>> {
+    const FN_PARSE_INT_GROUPED: &str = "parse_int_grouped";
+
+    let s0 = s.as_bytes();
+    let neg = s0.first() == Some(&b'-');
+    let sign_len = match s0.first() {
+        Some(b'+' | b'-') => 1,
+        _ => 0,
+    };
+
+    let bit_size = check_bit_size(FN_PARSE_INT_GROUPED, s0, bit_size)?;
+    // A value overflowing u64 is out of range for any bit size.
+    let un = parse_grouped(FN_PARSE_INT_GROUPED, s0, sign_len, grouping)?.unwrap_or(u64::MAX);
+
+    let cutoff = 1u64 << (bit_size - 1);
+    if !neg && (un >= cutoff) {
+        let bound_hint = (cutoff - 1) as i64;
+        let cause = NumErrorCause::OutOfRangeSigned { bound_hint };
+        return Err(NumError::new(FN_PARSE_INT_GROUPED, s0, cause));
+    }
+    if neg && (un > cutoff) {
+        let bound_hint = (cutoff as i64).wrapping_neg();
+        let cause = NumErrorCause::OutOfRangeSigned { bound_hint };
+        return Err(NumError::new(FN_PARSE_INT_GROUPED, s0, cause));
+    }
+
+    let n = un as i64;
+    Ok(if neg { n.wrapping_neg() } else { n })
+}
+
+/// Like [parse_int_grouped] but for unsigned numbers, as
+/// [parse_uint][crate::parse_uint] does.
+///
+/// A sign prefix is not permitted.
+pub fn parse_uint_grouped(s: &str, grouping: Grouping, bit_size: u8) -> Result<u64, NumError<'_>> {
+    const FN_PARSE_UINT_GROUPED: &str = "parse_uint_grouped";
+
+    let s0 = s.as_bytes();
+    let bit_size = check_bit_size(FN_PARSE_UINT_GROUPED, s0, bit_size)?;
+    let max_val = u64::MAX >> (u64::BITS - bit_size);
+    match parse_grouped(FN_PARSE_UINT_GROUPED, s0, 0, grouping)? {
+        Some(v) if v <= max_val => Ok(v),
+        _ => {
+            let cause = NumErrorCause::OutOfRangeUnsigned {
+                bound_hint: max_val,
+            };
+            Err(NumError::new(FN_PARSE_UINT_GROUPED, s0, cause))
+        }
+    }
+}
+
+/// Returns the string representation of i in base 10, with the digits grouped
+/// as given.
+///
+/// # Example
+/// ```
+/// use strconv::{Grouping, Locale};
+///
+/// assert_eq!(strconv::format_int_grouped(-1234567, Grouping::PERIOD), "-1.234.567");
+///
+/// let hi = Locale::lookup("hi").unwrap().grouping;
+/// assert_eq!(strconv::format_int_grouped(1234567, hi), "12,34,567");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn format_int_grouped(i: i64, grouping: Grouping) -> String {
+    format_grouped(i.unsigned_abs(), i < 0, grouping)
+}
+
+/// Like [format_int_grouped] but for unsigned integers.
+#[cfg(feature = "alloc")]
+pub fn format_uint_grouped(u: u64, grouping: Grouping) -> String {
+    format_grouped(u, false, grouping)
+}
+
+#[cfg(feature = "alloc")]
+fn format_grouped(u: u64, neg: bool, grouping: Grouping) -> String {
+    const FN_FORMAT_GROUPED: &str = "format_grouped";
+
+    let mut buf = [0u8; BUF_LEN];
+    let start = format_bits(FN_FORMAT_GROUPED, &mut buf, u, 10, false);
+    let digits = &buf[start..];
+
+    let (first, rest) = (grouping.first as usize, grouping.rest as usize);
+    let mut out = String::with_capacity(1 + digits.len() * 2);
+    if neg {
+        out.push('-');
+    }
+    for (i, &c) in digits.iter().enumerate() {
+        // The count of digits from c on to the end.
+        let left = digits.len() - i;
+        if (i != 0) && (left >= first) && ((left - first) % rest == 0) {
+            out.push(grouping.separator);
+        }
+        out.push(char::from(c));
+    }
+
+    out
+}
+
+fn check_bit_size<'a>(func: &'static str, s: &'a [u8], bit_size: u8) -> Result<u32, NumError<'a>> {
+    match bit_size {
+        0 => Ok(usize::BITS),
+        1..=64 => Ok(bit_size as u32),
+        _ => Err(NumError::bit_size(func, s, bit_size)),
+    }
+}
+
+/// Checks the grouped digits in s[start..], and returns their value, or
+/// `None` if it overflows u64.
+fn parse_grouped<'a>(
+    func: &'static str,
+    s: &'a [u8],
+    start: usize,
+    grouping: Grouping,
+) -> Result<Option<u64>, NumError<'a>> {
+    let mut sep = [0u8; 4];
+    let sep = grouping.separator.encode_utf8(&mut sep).as_bytes();
+    let (first, rest) = (grouping.first as usize, grouping.rest as usize);
+
+    let mut n = Some(0u64);
+    // The count of digits since the last separator, which is at last_sep.
+    let mut run = 0;
+    let mut last_sep = None;
+    let mut i = start;
+    while i < s.len() {
+        let c = s[i];
+        if c.is_ascii_digit() {
+            let d = (c - b'0') as u64;
+            n = n.and_then(|v| v.checked_mul(10)?.checked_add(d));
+            run += 1;
+            i += 1;
+            continue;
+        }
+
+        if !s[i..].starts_with(sep) {
+            return Err(NumError::syntax_at(func, s, i));
+        }
+
+        // The group ahead of a separator is the leftmost or a middle one.
+        let ok = match last_sep {
+            None => (1..=rest).contains(&run),
+            Some(_) => run == rest,
+        };
+        if !ok {
+            return Err(NumError::syntax_at(func, s, i));
+        }
+
+        last_sep = Some(i);
+        run = 0;
+        i += sep.len();
+    }
+
+    match last_sep {
+        Some(offset) if run != first => Err(NumError::syntax_at(func, s, offset)),
+        None if run == 0 => Err(NumError::syntax(func, s)),
+        _ => Ok(n),
+    }
+}
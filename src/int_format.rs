@@ -0,0 +1,249 @@
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+
+use crate::atoi::Unsigned;
+use crate::itoa::{ascii_str, check_base, count_digits, format_bits, BUF_LEN};
+
+/// Reusable options for formatting integers, which extend
+/// `format_int` by a minimum width, a fill character, a
+/// forced `+` sign, upper-case digits and a base prefix.
+///
+/// It's built by chaining the setters from [IntFormat::new], and can be
+/// stored (even as a `const`) to format any number of values.
+///
+/// # Example
+/// ```
+/// use strconv::IntFormat;
+///
+/// const REG: IntFormat = IntFormat::new(16)
+///     .width(9)
+///     .fill('0')
+///     .plus(true)
+///     .uppercase(true);
+///
+/// let mut s = String::new();
+/// REG.write_int(&mut s, 0x2a).unwrap();
+/// s.push(' ');
+/// REG.write_int(&mut s, -0xbeef).unwrap();
+/// s.push(' ');
+/// REG.prefix(true).write_uint(&mut s, 0xbeef).unwrap();
+/// assert_eq!(s, "+0000002A -0000BEEF +0x00BEEF");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IntFormat {
+    base: u8,
+    width: usize,
+    fill: char,
+    plus: bool,
+    upper: bool,
+    prefix: bool,
+}
+
+const FN_INT_FORMAT: &str = "IntFormat::format";
+
+impl IntFormat {
+    /// Returns the options for formatting in the given base (2 to 36) as
+    /// `format_int` does, i.e. without any padding, sign
+    /// for non-negative values or prefix, and in lower case.
+    ///
+    /// The base is checked when formatting, which panics for a base out of
+    /// range as `format_int` does.
+    pub const fn new(base: u8) -> Self {
+        Self {
+            base,
+            width: 0,
+            fill: ' ',
+            plus: false,
+            upper: false,
+            prefix: false,
+        }
+    }
+
+    /// Sets the minimum count of characters, which includes the sign and the
+    /// prefix. Shorter values are padded by the fill character.
+    pub const fn width(self, width: usize) -> Self {
+        Self { width, ..self }
+    }
+
+    /// Sets the character padding values up to the width, which is ' ' by
+    /// default. The padding goes ahead of the sign, unless the fill is '0',
+    /// which goes between the sign or prefix and the digits.
+    pub const fn fill(self, fill: char) -> Self {
+        Self { fill, ..self }
+    }
+
+    /// Sets whether non-negative values get a leading '+'.
+    pub const fn plus(self, plus: bool) -> Self {
+        Self { plus, ..self }
+    }
+
+    /// Sets whether digit values >= 10 use the upper-case letters 'A' to 'Z'.
+    pub const fn uppercase(self, upper: bool) -> Self {
+        Self { upper, ..self }
+    }
+
+    /// Sets whether the digits follow the prefix of the base, which is "0b",
+    /// "0o" or "0x" for base 2, 8 or 16 respectively. Other bases have none.
+    pub const fn prefix(self, prefix: bool) -> Self {
+        Self { prefix, ..self }
+    }
+
+    /// Returns the string representation of i under the options.
+    ///
+    /// # Example
+    /// ```
+    /// let f = strconv::IntFormat::new(10).width(6).fill('0');
+    /// assert_eq!(f.format_int(-42), "-00042");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn format_int(&self, i: i64) -> String {
+        let mut out = String::new();
+        self.render(i.unsigned_abs(), i < 0, |v| {
+            out.push_str(v);
+            Ok(())
+        })
+        .expect("String grows infallibly");
+        out
+    }
+
+    /// Returns the string representation of u under the options.
+    #[cfg(feature = "alloc")]
+    pub fn format_uint(&self, u: u64) -> String {
+        let mut out = String::new();
+        self.render(u, false, |v| {
+            out.push_str(v);
+            Ok(())
+        })
+        .expect("String grows infallibly");
+        out
+    }
+
+    /// Appends the string form of i, as generated by `IntFormat::format_int`,
+    /// to dst.
+    ///
+    /// # Example
+    /// ```
+    /// let f = strconv::IntFormat::new(16).prefix(true);
+    ///
+    /// let mut out = b"r0=".to_vec();
+    /// f.append_int(&mut out, 0xbeef);
+    /// assert_eq!(out, b"r0=0xbeef");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn append_int(&self, dst: &mut Vec<u8>, i: i64) {
+        self.render(i.unsigned_abs(), i < 0, |v| {
+            dst.extend_from_slice(v.as_bytes());
+            Ok(())
+        })
+        .expect("Vec grows infallibly");
+    }
+
+    /// Appends the string form of u, as generated by `IntFormat::format_uint`,
+    /// to dst.
+    #[cfg(feature = "alloc")]
+    pub fn append_uint(&self, dst: &mut Vec<u8>, u: u64) {
+        self.render(u, false, |v| {
+            dst.extend_from_slice(v.as_bytes());
+            Ok(())
+        })
+        .expect("Vec grows infallibly");
+    }
+
+    /// Writes the string form of i, as generated by `IntFormat::format_int`,
+    /// to w without allocating.
+    pub fn write_int<W>(&self, w: &mut W, i: i64) -> fmt::Result
+    where
+        W: fmt::Write + ?Sized,
+    {
+        self.render(i.unsigned_abs(), i < 0, |v| w.write_str(v))
+    }
+
+    /// Writes the string form of u, as generated by `IntFormat::format_uint`,
+    /// to w without allocating.
+    pub fn write_uint<W>(&self, w: &mut W, u: u64) -> fmt::Result
+    where
+        W: fmt::Write + ?Sized,
+    {
+        self.render(u, false, |v| w.write_str(v))
+    }
+
+    /// Returns the length in bytes of `IntFormat::format_int(i)` without
+    /// formatting it, which counts the padding as well.
+    ///
+    /// # Example
+    /// ```
+    /// let f = strconv::IntFormat::new(16).prefix(true);
+    /// assert_eq!(f.formatted_len(-255), 5);
+    /// assert_eq!(f.width(8).fill('·').formatted_len(-255), 11);
+    /// ```
+    pub fn formatted_len(&self, i: i64) -> usize {
+        self.len(i.unsigned_abs().into(), i < 0)
+    }
+
+    /// Returns the length in bytes of `IntFormat::format_uint(u)` without
+    /// formatting it.
+    pub fn formatted_uint_len(&self, u: u64) -> usize {
+        self.len(u.into(), false)
+    }
+
+    fn len(&self, u: u128, neg: bool) -> usize {
+        check_base(FN_INT_FORMAT, self.base);
+        let sign = usize::from(neg || self.plus);
+        let prefix = match (self.prefix, self.base) {
+            (true, 2 | 8 | 16) => 2,
+            _ => 0,
+        };
+
+        let len = sign + prefix + count_digits(u, self.base);
+        let padding = self.width.saturating_sub(len);
+        len + padding * self.fill.len_utf8()
+    }
+
+    /// Hands the pieces of the rendering of u, which is negated if neg is set,
+    /// over to out in order.
+    fn render<U, F>(&self, u: U, neg: bool, mut out: F) -> fmt::Result
+    where
+        U: Unsigned,
+        F: FnMut(&str) -> fmt::Result,
+    {
+        let mut buf = [0u8; BUF_LEN];
+        let start = format_bits(FN_INT_FORMAT, &mut buf, u, self.base, false);
+        if self.upper {
+            buf[start..].make_ascii_uppercase();
+        }
+        let digits = ascii_str(&buf[start..]);
+
+        let sign = match (neg, self.plus) {
+            (true, _) => "-",
+            (false, true) => "+",
+            (false, false) => "",
+        };
+        let prefix = match (self.prefix, self.base) {
+            (true, 2) => "0b",
+            (true, 8) => "0o",
+            (true, 16) => "0x",
+            _ => "",
+        };
+
+        let len = sign.len() + prefix.len() + digits.len();
+        let padding = self.width.saturating_sub(len);
+        let mut fill = [0u8; 4];
+        let fill = &*self.fill.encode_utf8(&mut fill);
+
+        if self.fill == '0' {
+            out(sign)?;
+            out(prefix)?;
+            for _ in 0..padding {
+                out(fill)?;
+            }
+        } else {
+            for _ in 0..padding {
+                out(fill)?;
+            }
+            out(sign)?;
+            out(prefix)?;
+        }
+        out(digits)
+    }
+}
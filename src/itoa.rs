@@ -0,0 +1,435 @@
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+
+use crate::atoi::Unsigned;
+use crate::NumErrorCause;
+
+const DIGITS: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+const N_SMALLS: u64 = 100;
+const SMALLS: &[u8; 200] = b"\
+    00010203040506070809\
+    10111213141516171819\
+    20212223242526272829\
+    30313233343536373839\
+    40414243444546474849\
+    50515253545556575859\
+    60616263646566676869\
+    70717273747576777879\
+    80818283848586878889\
+    90919293949596979899";
+
+/// The length of a buffer fitting any number rendered by [format_bits], which
+/// is 128 binary digits and a sign.
+pub(crate) const BUF_LEN: usize = 128 + 1;
+
+/// Returns the string representation of i in the given base,
+/// for 2 <= base <= 36. The result uses the lower-case letters 'a' to 'z'
+/// for digit values >= 10.
+///
+/// # Panics
+/// It panics if base is out of range, with a message naming the cause as
+/// [NumErrorCause::InvalidBase][crate::NumErrorCause::InvalidBase] does, e.g.
+/// `strconv.format_int: invalid base 1`.
+///
+/// # Example
+/// ```
+#[doc = include_str!("../examples/format_int.rs")]
+/// ```
+#[cfg(feature = "alloc")]
+pub fn format_int(i: i64, base: u8) -> String {
+    const FN_FORMAT_INT: &str = "format_int";
+
+    let mut buf = [0u8; BUF_LEN];
+    String::from(int_str(FN_FORMAT_INT, &mut buf, i, base))
+}
+
+/// Returns the string representation of u in the given base,
+/// for 2 <= base <= 36. The result uses the lower-case letters 'a' to 'z'
+/// for digit values >= 10.
+///
+/// # Panics
+/// It panics if base is out of range, as [format_int] does.
+///
+/// # Example
+/// ```
+#[doc = include_str!("../examples/format_uint.rs")]
+/// ```
+#[cfg(feature = "alloc")]
+pub fn format_uint(u: u64, base: u8) -> String {
+    const FN_FORMAT_UINT: &str = "format_uint";
+
+    let mut buf = [0u8; BUF_LEN];
+    String::from(uint_str(FN_FORMAT_UINT, &mut buf, u, base))
+}
+
+/// Returns the two's complement bit pattern of i at `bit_size` bits (0 to 64,
+/// where 0 means the size of isize) in the given base, which must be a power
+/// of two. The digits are padded with zeros to the width of `bit_size` bits,
+/// and i is truncated to `bit_size` bits as by an `as` cast.
+///
+/// The result is parsed back by
+/// [parse_twos_complement][crate::parse_twos_complement] for the same base
+/// and `bit_size`.
+///
+/// # Panics
+/// It panics if base isn't a power of two from 2 to 32, or `bit_size` is
+/// above 64, as [format_int] does.
+///
+/// # Example
+/// ```
+/// assert_eq!(strconv::format_twos_complement(-1, 16, 32), "ffffffff");
+/// assert_eq!(strconv::format_twos_complement(-2, 2, 8), "11111110");
+/// assert_eq!(strconv::format_twos_complement(42, 16, 16), "002a");
+///
+/// let s = strconv::format_twos_complement(i64::MIN, 8, 64);
+/// assert_eq!(s, "1000000000000000000000");
+/// assert_eq!(strconv::parse_twos_complement(&s, 8, 64), Ok(i64::MIN));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn format_twos_complement(i: i64, base: u8, bit_size: u8) -> String {
+    const FN_FORMAT_TWOS_COMPLEMENT: &str = "format_twos_complement";
+
+    if !base.is_power_of_two() {
+        let err = NumErrorCause::InvalidBase(base);
+        panic!("strconv.{}: {}", FN_FORMAT_TWOS_COMPLEMENT, err);
+    }
+
+    let bits = match bit_size {
+        0 => usize::BITS,
+        1..=64 => bit_size as u32,
+        _ => {
+            let err = NumErrorCause::InvalidBitSize(bit_size);
+            panic!("strconv.{}: {}", FN_FORMAT_TWOS_COMPLEMENT, err);
+        }
+    };
+
+    let u = (i as u64) & (u64::MAX >> (u64::BITS - bits));
+    let mut buf = [0u8; BUF_LEN];
+    let start = format_bits(FN_FORMAT_TWOS_COMPLEMENT, &mut buf, u, base, false);
+
+    let shift = base.trailing_zeros();
+    let width = ((bits + shift - 1) / shift) as usize;
+    let digits = &buf[start..];
+    let mut out = String::with_capacity(width);
+    for _ in digits.len()..width {
+        out.push('0');
+    }
+    out.push_str(ascii_str(digits));
+    out
+}
+
+/// Equivalent to [format_int]\(i as i64, 10).
+///
+/// # Example
+/// ```
+/// assert_eq!(strconv::itoa(-42), "-42");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn itoa(i: isize) -> String {
+    format_int(i as i64, 10)
+}
+
+/// Returns the count of digits representing u in the given base,
+/// for 2 <= base <= 36, without formatting it. That is the length of
+/// `format_uint(u, base)` for a u64, and it is 1 for 0.
+///
+/// # Panics
+/// It panics if base is out of range, as `format_int` does.
+///
+/// # Example
+/// ```
+/// assert_eq!(strconv::digit_count(0u8, 10), 1);
+/// assert_eq!(strconv::digit_count(999u64, 10), 3);
+/// assert_eq!(strconv::digit_count(1000u64, 10), 4);
+/// assert_eq!(strconv::digit_count(u128::MAX, 2), 128);
+/// ```
+pub fn digit_count<U>(u: U, base: u8) -> usize
+where
+    U: Into<u128>,
+{
+    const FN_DIGIT_COUNT: &str = "digit_count";
+
+    check_base(FN_DIGIT_COUNT, base);
+    count_digits(u.into(), base)
+}
+
+/// Returns the length of `format_int(i, base)` without formatting it, which
+/// is the count of digits along with the sign.
+///
+/// # Panics
+/// It panics if base is out of range, as `format_int` does.
+///
+/// # Example
+/// ```
+/// assert_eq!(strconv::formatted_len(-255, 16), 3);
+/// assert_eq!(strconv::formatted_len(i64::MIN, 2), 65);
+/// ```
+pub fn formatted_len(i: i64, base: u8) -> usize {
+    const FN_FORMATTED_LEN: &str = "formatted_len";
+
+    check_base(FN_FORMATTED_LEN, base);
+    usize::from(i < 0) + count_digits(i.unsigned_abs().into(), base)
+}
+
+/// Appends the string form of the integer i, as generated by [format_int],
+/// to dst.
+///
+/// # Panics
+/// It panics if base is out of range, as [format_int] does.
+///
+/// # Example
+/// ```
+/// let mut b = b"int (base 10):".to_vec();
+/// strconv::append_int(&mut b, -42, 10);
+/// assert_eq!(b, b"int (base 10):-42");
+///
+/// let mut b = b"int (base 16):".to_vec();
+/// strconv::append_int(&mut b, -42, 16);
+/// assert_eq!(b, b"int (base 16):-2a");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn append_int(dst: &mut Vec<u8>, i: i64, base: u8) {
+    const FN_APPEND_INT: &str = "append_int";
+
+    let mut buf = [0u8; BUF_LEN];
+    dst.extend_from_slice(int_str(FN_APPEND_INT, &mut buf, i, base).as_bytes());
+}
+
+/// Appends the string form of the unsigned integer u, as generated by
+/// [format_uint], to dst.
+///
+/// # Panics
+/// It panics if base is out of range, as [format_int] does.
+///
+/// # Example
+/// ```
+/// let mut b = b"uint (base 16):".to_vec();
+/// strconv::append_uint(&mut b, 42, 16);
+/// assert_eq!(b, b"uint (base 16):2a");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn append_uint(dst: &mut Vec<u8>, u: u64, base: u8) {
+    const FN_APPEND_UINT: &str = "append_uint";
+
+    let mut buf = [0u8; BUF_LEN];
+    dst.extend_from_slice(uint_str(FN_APPEND_UINT, &mut buf, u, base).as_bytes());
+}
+
+/// Writes the string form of the integer i, as generated by `format_int`, to
+/// w without allocating.
+///
+/// # Panics
+/// It panics if base is out of range, as `format_int` does.
+///
+/// # Example
+/// ```
+/// use core::fmt::Write;
+///
+/// let mut s = String::from("offset: ");
+/// strconv::write_int(&mut s, -0x1f, 16).unwrap();
+/// write!(s, " bytes").unwrap();
+/// assert_eq!(s, "offset: -1f bytes");
+/// ```
+pub fn write_int<W>(w: &mut W, i: i64, base: u8) -> fmt::Result
+where
+    W: fmt::Write + ?Sized,
+{
+    const FN_WRITE_INT: &str = "write_int";
+
+    let mut buf = [0u8; BUF_LEN];
+    w.write_str(int_str(FN_WRITE_INT, &mut buf, i, base))
+}
+
+/// Like [write_int] but for unsigned integers, as generated by `format_uint`.
+pub fn write_uint<W>(w: &mut W, u: u64, base: u8) -> fmt::Result
+where
+    W: fmt::Write + ?Sized,
+{
+    const FN_WRITE_UINT: &str = "write_uint";
+
+    let mut buf = [0u8; BUF_LEN];
+    w.write_str(uint_str(FN_WRITE_UINT, &mut buf, u, base))
+}
+
+/// Like [write_int] but writes to a [std::io::Write].
+///
+/// # Example
+/// ```
+/// let mut out = Vec::new();
+/// strconv::write_int_io(&mut out, -42, 2).unwrap();
+/// assert_eq!(out, b"-101010");
+/// ```
+#[cfg(feature = "std")]
+pub fn write_int_io<W>(w: &mut W, i: i64, base: u8) -> std::io::Result<()>
+where
+    W: std::io::Write + ?Sized,
+{
+    const FN_WRITE_INT_IO: &str = "write_int_io";
+
+    let mut buf = [0u8; BUF_LEN];
+    w.write_all(int_str(FN_WRITE_INT_IO, &mut buf, i, base).as_bytes())
+}
+
+/// Like [write_uint] but writes to a [std::io::Write].
+#[cfg(feature = "std")]
+pub fn write_uint_io<W>(w: &mut W, u: u64, base: u8) -> std::io::Result<()>
+where
+    W: std::io::Write + ?Sized,
+{
+    const FN_WRITE_UINT_IO: &str = "write_uint_io";
+
+    let mut buf = [0u8; BUF_LEN];
+    w.write_all(uint_str(FN_WRITE_UINT_IO, &mut buf, u, base).as_bytes())
+}
+
+/// Returns the string form of i in the given base, which is rendered into buf
+/// unless it's a small number.
+fn int_str<'b>(func: &'static str, buf: &'b mut [u8; BUF_LEN], i: i64, base: u8) -> &'b str {
+    if (0..N_SMALLS as i64).contains(&i) && (base == 10) {
+        return small(i as usize);
+    }
+
+    let start = format_bits(func, buf, i.unsigned_abs(), base, i < 0);
+    ascii_str(&buf[start..])
+}
+
+/// Like [int_str] but for unsigned integers.
+fn uint_str<'b>(func: &'static str, buf: &'b mut [u8; BUF_LEN], u: u64, base: u8) -> &'b str {
+    if (u < N_SMALLS) && (base == 10) {
+        return small(u as usize);
+    }
+
+    let start = format_bits(func, buf, u, base, false);
+    ascii_str(&buf[start..])
+}
+
+/// Returns the string for an i with 0 <= i < N_SMALLS.
+fn small(i: usize) -> &'static str {
+    let s = &SMALLS[i * 2..i * 2 + 2];
+    if i < 10 {
+        ascii_str(&s[1..])
+    } else {
+        ascii_str(s)
+    }
+}
+
+/// Returns the count of digits representing u in a base, which is in range.
+pub(crate) fn count_digits(u: u128, base: u8) -> usize {
+    if base.is_power_of_two() {
+        let shift = base.trailing_zeros();
+        let bits = (u128::BITS - u.leading_zeros()).max(1);
+        return ((bits + shift - 1) / shift) as usize;
+    }
+
+    // Fast path for values fitting in 64 bits, which divide faster.
+    if let Ok(small) = u64::try_from(u) {
+        return count_by_division(small, base);
+    }
+    count_by_division(u, base)
+}
+
+fn count_by_division<U>(u: U, base: u8) -> usize
+where
+    U: Unsigned,
+{
+    let b = U::from(base);
+    let mut u = u;
+    let mut n = 1;
+    while u >= b {
+        u = u / b;
+        n += 1;
+    }
+    n
+}
+
+/// Panics if base is out of range, naming func as the failing function.
+pub(crate) fn check_base(func: &'static str, base: u8) {
+    if !(2..=36).contains(&base) {
+        panic!("strconv.{}: {}", func, NumErrorCause::InvalidBase(base));
+    }
+}
+
+/// Views the rendered digits as a string, which they are since all of them
+/// are ASCII.
+pub(crate) fn ascii_str(s: &[u8]) -> &str {
+    core::str::from_utf8(s).expect("digits are ASCII")
+}
+
+/// Renders u in the given base at the end of buf, preceded by a '-' if neg is
+/// set, and returns the index where the rendering starts.
+///
+/// It panics if base is out of range, naming func as the failing function.
+pub(crate) fn format_bits<U>(
+    func: &'static str,
+    buf: &mut [u8; BUF_LEN],
+    u: U,
+    base: u8,
+    neg: bool,
+) -> usize
+where
+    U: Unsigned,
+{
+    check_base(func, base);
+
+    // Assemble the digits right to left.
+    let mut i = buf.len();
+    let mut u = u;
+
+    if base == 10 {
+        // Convert two digits at a time.
+        let hundred = U::from(100u8);
+        while u >= hundred {
+            let is = (u % hundred).as_usize() * 2;
+            u = u / hundred;
+            i -= 2;
+            buf[i + 1] = SMALLS[is + 1];
+            buf[i] = SMALLS[is];
+        }
+
+        // u < 100
+        let is = u.as_usize() * 2;
+        i -= 1;
+        buf[i] = SMALLS[is + 1];
+        if is >= 20 {
+            i -= 1;
+            buf[i] = SMALLS[is];
+        }
+    } else if base.is_power_of_two() {
+        // Use shifts and masks instead of division.
+        let shift = base.trailing_zeros();
+        let b = U::from(base);
+        let m = base as usize - 1; // == 1<<shift - 1
+        while u >= b {
+            i -= 1;
+            buf[i] = DIGITS[u.as_usize() & m];
+            u = u >> shift;
+        }
+
+        // u < base
+        i -= 1;
+        buf[i] = DIGITS[u.as_usize()];
+    } else {
+        // general case
+        let b = U::from(base);
+        while u >= b {
+            i -= 1;
+            let q = u / b;
+            buf[i] = DIGITS[(u - q * b).as_usize()];
+            u = q;
+        }
+
+        // u < base
+        i -= 1;
+        buf[i] = DIGITS[u.as_usize()];
+    }
+
+    // Add sign, if any.
+    if neg {
+        i -= 1;
+        buf[i] = b'-';
+    }
+
+    i
+}
@@ -1,7 +1,46 @@
 //! Crate strconv implements conversions to and from string representations of basic data types.
+//!
+//! # Features
+//! - `std` (default): implements `std::error::Error` for the errors, adds the
+//!   formatting functions writing to `std::io::Write`, and enables `alloc`.
+//! - `alloc`: records the input of [NumError] as `Cow<str>` and enables
+//!   `NumError::into_owned` and the formatting functions returning `String` or
+//!   appending to `Vec<u8>`.
+//!
+//! With both disabled, the crate is `no_std` and never allocates.
 
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::needless_doctest_main)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+mod atob;
+mod atof;
 mod atoi;
+mod atoi_const;
+mod decimal;
 mod errors;
+mod format;
+mod grouping;
+mod int_format;
+mod itoa;
+#[cfg(feature = "alloc")]
+mod literal;
+mod parse;
+mod radix;
+mod swar;
 
+pub use atob::*;
+pub use atof::*;
 pub use atoi::*;
+pub use atoi_const::*;
 pub use errors::*;
+pub use format::*;
+pub use grouping::*;
+pub use int_format::*;
+pub use itoa::*;
+#[cfg(feature = "alloc")]
+pub use literal::*;
+pub use parse::*;
+pub use radix::*;
@@ -0,0 +1,136 @@
+use alloc::string::String;
+
+use crate::itoa::{count_digits, format_bits, BUF_LEN};
+
+/// How [format_literal] renders integers, which is by the base prefix and the
+/// grouping of digits.
+///
+/// # Example
+/// ```
+/// use strconv::LiteralStyle;
+///
+/// assert_eq!(strconv::format_literal(0xdeadbeef, LiteralStyle::HEX), "0xdeadbeef");
+/// assert_eq!(
+///     strconv::format_literal(0xdeadbeef, LiteralStyle::HEX.grouped(4)),
+///     "0xdead_beef"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LiteralStyle {
+    prefix: &'static str,
+    base: u8,
+    group: u8,
+}
+
+impl LiteralStyle {
+    /// Binary digits after "0b".
+    pub const BINARY: Self = Self::new("0b", 2);
+    /// Octal digits after "0o".
+    pub const OCTAL: Self = Self::new("0o", 8);
+    /// Octal digits after a bare "0", as in C.
+    pub const LEGACY_OCTAL: Self = Self::new("0", 8);
+    /// Hexadecimal digits after "0x".
+    pub const HEX: Self = Self::new("0x", 16);
+
+    const fn new(prefix: &'static str, base: u8) -> Self {
+        Self {
+            prefix,
+            base,
+            group: 0,
+        }
+    }
+
+    /// Returns the style separating every n digits by an underscore, counting
+    /// from the last digit. Digits aren't grouped if n is 0.
+    pub const fn grouped(self, n: u8) -> Self {
+        Self { group: n, ..self }
+    }
+
+    /// Returns the length of [format_literal]\(i, self) without formatting
+    /// it.
+    ///
+    /// # Example
+    /// ```
+    /// use strconv::LiteralStyle;
+    ///
+    /// assert_eq!(LiteralStyle::BINARY.grouped(4).formatted_len(-1000), 15);
+    /// assert_eq!(LiteralStyle::LEGACY_OCTAL.formatted_len(0), 1);
+    /// ```
+    pub fn formatted_len(self, i: i64) -> usize {
+        usize::from(i < 0) + self.len(i.unsigned_abs())
+    }
+
+    /// Returns the length of [format_uint_literal]\(u, self) without
+    /// formatting it.
+    pub fn formatted_uint_len(self, u: u64) -> usize {
+        self.len(u)
+    }
+
+    fn len(self, u: u64) -> usize {
+        if (u == 0) && (self.prefix == "0") {
+            return 1;
+        }
+
+        let digits = count_digits(u.into(), self.base);
+        let separators = match self.group as usize {
+            0 => 0,
+            group => (digits - 1) / group,
+        };
+        self.prefix.len() + digits + separators
+    }
+}
+
+/// Returns i as an integer literal of the given style, which is parsed back
+/// by [parse_int][crate::parse_int] with base 0.
+///
+/// A negative i gets a leading "-". Underscores are only put between digits,
+/// so they are always accepted by the base 0 parsing. The literal of 0 in
+/// [LiteralStyle::LEGACY_OCTAL] is "0".
+///
+/// # Example
+/// ```
+/// use strconv::LiteralStyle;
+///
+/// let s = strconv::format_literal(-0o755, LiteralStyle::LEGACY_OCTAL);
+/// assert_eq!(s, "-0755");
+/// assert_eq!(strconv::parse_int(&s, 0, 64), Ok(-0o755));
+///
+/// let s = strconv::format_literal(-1000, LiteralStyle::BINARY.grouped(4));
+/// assert_eq!(s, "-0b11_1110_1000");
+/// ```
+pub fn format_literal(i: i64, style: LiteralStyle) -> String {
+    literal(i.unsigned_abs(), i < 0, style)
+}
+
+/// Like [format_literal] but for unsigned integers, which are parsed back by
+/// [parse_uint][crate::parse_uint] with base 0.
+pub fn format_uint_literal(u: u64, style: LiteralStyle) -> String {
+    literal(u, false, style)
+}
+
+fn literal(u: u64, neg: bool, style: LiteralStyle) -> String {
+    const FN_FORMAT_LITERAL: &str = "format_literal";
+
+    let mut buf = [0u8; BUF_LEN];
+    let start = format_bits(FN_FORMAT_LITERAL, &mut buf, u, style.base, false);
+    let digits = match &buf[start..] {
+        // The prefix of legacy octal is a valid literal of 0 already.
+        b"0" if style.prefix == "0" => &[],
+        v => v,
+    };
+
+    let group = style.group as usize;
+    let mut out = String::with_capacity(1 + style.prefix.len() + digits.len() * 2);
+    if neg {
+        out.push('-');
+    }
+    out.push_str(style.prefix);
+    for (i, &c) in digits.iter().enumerate() {
+        if (group != 0) && (i != 0) && ((digits.len() - i) % group == 0) {
+            out.push('_');
+        }
+        out.push(char::from(c));
+    }
+
+    out
+}
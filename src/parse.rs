@@ -0,0 +1,116 @@
+use crate::{
+    parse_int128_bytes, parse_int_bytes, parse_uint128_bytes, parse_uint_bytes, NumError,
+    NumErrorCause,
+};
+
+/// Integer types which can be parsed from a string in a given base.
+///
+/// The bit size handed over to [parse_int][crate::parse_int] or
+/// [parse_uint][crate::parse_uint] is derived from the implementing type,
+/// so the parsed value always fits into it.
+pub trait ParseRadix: Sized {
+    /// The `bit_size` matching the type.
+    const BIT_SIZE: u8;
+
+    /// Interprets the bytes s in the given base (0, 2 to 36).
+    ///
+    /// See [parse_int_bytes][crate::parse_int_bytes] for the accepted syntax.
+    fn parse_radix_bytes(s: &[u8], base: u8) -> Result<Self, NumError<'_>>;
+
+    /// Extracts the bound hint of an out-of-range error as the implementing type.
+    ///
+    /// It returns `None` if cause isn't a range error of the same signedness, or
+    /// the hint doesn't fit into the type.
+    fn from_bound_hint(cause: &NumErrorCause) -> Option<Self>;
+}
+
+/// Interprets a string s in the given base (0, 2 to 36) and returns the
+/// corresponding value of type `T`.
+///
+/// It works as [parse_int][crate::parse_int] for signed `T` and
+/// [parse_uint][crate::parse_uint] for unsigned `T`, with `bit_size` derived
+/// from `T`. Errors are reported the same way, so range errors carry the
+/// bound of `T` as hint, which can be read back by
+/// [NumErrorCause::bound_hint][crate::NumErrorCause::bound_hint].
+///
+/// # Example
+/// ```
+/// let got: i8 = strconv::parse("-0x80", 0).unwrap();
+/// assert_eq!(got, i8::MIN);
+///
+/// let err = strconv::parse::<u16>("65536", 10).unwrap_err();
+/// assert_eq!(err.err.bound_hint(), Some(u16::MAX));
+/// ```
+pub fn parse<T>(s: &str, base: u8) -> Result<T, NumError<'_>>
+where
+    T: ParseRadix,
+{
+    T::parse_radix_bytes(s.as_bytes(), base)
+}
+
+/// Like [parse] but reads the number from a byte slice, which isn't required
+/// to be valid UTF-8.
+pub fn parse_bytes<T>(s: &[u8], base: u8) -> Result<T, NumError<'_>>
+where
+    T: ParseRadix,
+{
+    T::parse_radix_bytes(s, base)
+}
+
+macro_rules! impl_parse_radix_signed {
+    ($parse:ident: $($t:ty),*) => {
+        $(
+            impl ParseRadix for $t {
+                const BIT_SIZE: u8 = <$t>::BITS as u8;
+
+                fn parse_radix_bytes(s: &[u8], base: u8) -> Result<Self, NumError<'_>> {
+                    $parse(s, base, Self::BIT_SIZE).map(|v| v as Self)
+                }
+
+                fn from_bound_hint(cause: &NumErrorCause) -> Option<Self> {
+                    match cause {
+                        NumErrorCause::OutOfRangeSigned { bound_hint } => {
+                            Self::try_from(*bound_hint).ok()
+                        }
+                        NumErrorCause::OutOfRangeSigned128 { bound_hint } => {
+                            Self::try_from(*bound_hint).ok()
+                        }
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_parse_radix_unsigned {
+    ($parse:ident: $($t:ty),*) => {
+        $(
+            impl ParseRadix for $t {
+                const BIT_SIZE: u8 = <$t>::BITS as u8;
+
+                fn parse_radix_bytes(s: &[u8], base: u8) -> Result<Self, NumError<'_>> {
+                    $parse(s, base, Self::BIT_SIZE).map(|v| v as Self)
+                }
+
+                fn from_bound_hint(cause: &NumErrorCause) -> Option<Self> {
+                    match cause {
+                        NumErrorCause::OutOfRangeUnsigned { bound_hint } => {
+                            Self::try_from(*bound_hint).ok()
+                        }
+                        NumErrorCause::OutOfRangeUnsigned128 { bound_hint } => {
+                            Self::try_from(*bound_hint).ok()
+                        }
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_parse_radix_signed!(parse_int_bytes: i8, i16, i32, i64, isize);
+impl_parse_radix_signed!(parse_int128_bytes: i128);
+
+impl_parse_radix_unsigned!(parse_uint_bytes: u8, u16, u32, u64, usize);
+impl_parse_radix_unsigned!(parse_uint128_bytes: u128);
@@ -0,0 +1,180 @@
+use core::fmt;
+
+use crate::itoa::{ascii_str, BUF_LEN};
+use crate::{FormatBuffer, FormatRadix, NumErrorCause};
+
+/// An integer displayed in a base from 2 to 36, as returned by [radix].
+///
+/// It renders on the stack when formatted, so it never allocates. Its
+/// [Display][fmt::Display] (and [Debug][fmt::Debug]) output is the string
+/// representation given by `format_int`, and honors the width, fill,
+/// alignment, `+` and `0` flags of the formatter as the primitive integers
+/// do. The `#` flag adds the prefix "0b", "0o" or "0x" in
+/// base 2, 8 or 16 respectively, and nothing in other bases.
+///
+/// Digit values >= 10 are rendered as the lower-case letters 'a' to 'z',
+/// or as the upper-case ones after [uppercase][Radix::uppercase], in any
+/// base. In base 16, [LowerHex][fmt::LowerHex] and [UpperHex][fmt::UpperHex]
+/// choose the case as well, so `{:X}` renders 'A' to 'F'. Unlike the
+/// primitive integers, negative values keep their sign rather than showing
+/// their two's complement.
+///
+/// # Panics
+/// Formatting by `{:x}` or `{:X}` panics in any base other than 16, e.g.
+/// `strconv.radix: invalid base 10 for hex formatting`.
+///
+/// # Example
+/// ```
+/// use strconv::radix;
+///
+/// assert_eq!(format!("{}", radix(-255i16, 16)), "-ff");
+/// assert_eq!(format!("{}", radix(-255i16, 16).uppercase()), "-FF");
+/// assert_eq!(format!("{:#010x}", radix(255u8, 16)), "0x000000ff");
+/// assert_eq!(format!("{:X}", radix(-0xbeefi32, 16)), "-BEEF");
+/// assert_eq!(format!("{:*^6}", radix(1295u16, 36)), "**zz**");
+/// assert_eq!(format!("{:+}", radix(5, 2)), "+101");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Radix<T> {
+    value: T,
+    base: u8,
+    upper: bool,
+}
+
+/// Wraps value for formatting in the given base, for 2 <= base <= 36, by the
+/// standard formatting macros such as `write!` or `format!`.
+///
+/// # Panics
+/// It panics if base is out of range, as `format_int`
+/// does, e.g. `strconv.radix: invalid base 37`.
+///
+/// # Example
+/// ```
+/// use core::fmt::Write;
+///
+/// let mut s = String::new();
+/// write!(s, "{}|{:>6}", strconv::radix(-42, 10), strconv::radix(1295u16, 36)).unwrap();
+/// assert_eq!(s, "-42|    zz");
+/// ```
+pub fn radix<T>(value: T, base: u8) -> Radix<T>
+where
+    T: FormatRadix,
+{
+    const FN_RADIX: &str = "radix";
+
+    if !(2..=36).contains(&base) {
+        panic!("strconv.{}: {}", FN_RADIX, NumErrorCause::InvalidBase(base));
+    }
+    Radix {
+        value,
+        base,
+        upper: false,
+    }
+}
+
+impl<T> Radix<T>
+where
+    T: FormatRadix,
+{
+    /// Returns the wrapped value.
+    pub fn value(self) -> T {
+        self.value
+    }
+
+    /// Returns the base the value is displayed in.
+    pub fn base(self) -> u8 {
+        self.base
+    }
+
+    /// Returns the adapter rendering digit values >= 10 as the upper-case
+    /// letters 'A' to 'Z'. Any prefix added by the `#` flag stays in lower
+    /// case, as for the primitive integers.
+    ///
+    /// # Example
+    /// ```
+    /// use strconv::radix;
+    ///
+    /// assert_eq!(format!("{:#}", radix(0xbeefu16, 16).uppercase()), "0xBEEF");
+    /// assert_eq!(format!("{:>4}", radix(1295, 36).uppercase()), "  ZZ");
+    /// ```
+    pub fn uppercase(self) -> Self {
+        Self {
+            upper: true,
+            ..self
+        }
+    }
+
+    fn pad(&self, f: &mut fmt::Formatter<'_>, upper: bool) -> fmt::Result {
+        let mut buf = FormatBuffer::new();
+        let s = buf.format(self.value, self.base);
+        let (non_neg, digits) = match s.strip_prefix('-') {
+            Some(digits) => (false, digits),
+            None => (true, s),
+        };
+        let prefix = match self.base {
+            2 => "0b",
+            8 => "0o",
+            16 => "0x",
+            _ => "",
+        };
+
+        if !(self.upper || upper) {
+            return f.pad_integral(non_neg, prefix, digits);
+        }
+        let mut upper = [0u8; BUF_LEN];
+        let upper = &mut upper[..digits.len()];
+        upper.copy_from_slice(digits.as_bytes());
+        upper.make_ascii_uppercase();
+        f.pad_integral(non_neg, prefix, ascii_str(upper))
+    }
+
+    fn check_hex(&self) {
+        const FN_RADIX: &str = "radix";
+
+        if self.base != 16 {
+            panic!(
+                "strconv.{}: {} for hex formatting",
+                FN_RADIX,
+                NumErrorCause::InvalidBase(self.base)
+            );
+        }
+    }
+}
+
+impl<T> fmt::Display for Radix<T>
+where
+    T: FormatRadix,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.pad(f, false)
+    }
+}
+
+impl<T> fmt::Debug for Radix<T>
+where
+    T: FormatRadix,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.pad(f, false)
+    }
+}
+
+impl<T> fmt::LowerHex for Radix<T>
+where
+    T: FormatRadix,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.check_hex();
+        self.pad(f, false)
+    }
+}
+
+impl<T> fmt::UpperHex for Radix<T>
+where
+    T: FormatRadix,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.check_hex();
+        self.pad(f, true)
+    }
+}
@@ -0,0 +1,53 @@
+//! Conversion of 8 digits at once, treating them as the lanes of a u64
+//! ("SIMD within a register").
+
+const ONES: u64 = 0x0101_0101_0101_0101;
+const HIGHS: u64 = 0x8080_8080_8080_8080;
+
+/// Returns the value of the 8 decimal digits in s, or `None` if any byte of s
+/// isn't a decimal digit.
+pub(crate) fn parse8_dec(s: &[u8; 8]) -> Option<u64> {
+    let v = u64::from_le_bytes(*s);
+    if in_range(v, b'0', b'9') != HIGHS {
+        return None;
+    }
+
+    // Merge the digits pairwise into 2-, 4- and finally 8-digit numbers. The
+    // first digit sits in the lowest byte of the little-endian load.
+    const MASK: u64 = 0x0000_00ff_0000_00ff;
+    const MUL1: u64 = 100 + (1_000_000 << 32);
+    const MUL2: u64 = 1 + (10_000 << 32);
+
+    let v = v - 0x3030_3030_3030_3030;
+    let v = (v * 10) + (v >> 8);
+    let v1 = (v & MASK).wrapping_mul(MUL1);
+    let v2 = ((v >> 16) & MASK).wrapping_mul(MUL2);
+    Some((v1.wrapping_add(v2) >> 32) as u32 as u64)
+}
+
+/// Returns the value of the 8 hexadecimal digits in s, or `None` if any byte
+/// of s isn't a hexadecimal digit. Letters may be of either case.
+pub(crate) fn parse8_hex(s: &[u8; 8]) -> Option<u64> {
+    let v = u64::from_be_bytes(*s);
+    let decimals = in_range(v, b'0', b'9');
+    let letters = in_range(v | (ONES * 0x20), b'a', b'f');
+    if (decimals | letters) != HIGHS {
+        return None;
+    }
+
+    // Turn each byte into its nibble, then pack the nibbles pairwise. The
+    // first digit sits in the highest byte of the big-endian load.
+    let v = (v & (ONES * 0x0f)) + ((letters >> 7) * 9);
+    let v = (v | (v >> 4)) & 0x00ff_00ff_00ff_00ff;
+    let v = (v | (v >> 8)) & 0x0000_ffff_0000_ffff;
+    Some((v | (v >> 16)) & 0xffff_ffff)
+}
+
+/// Sets the high bit of every byte of v within lo..=hi, and clears all other
+/// bits. The bytes are checked separately, so no carry crosses them.
+fn in_range(v: u64, lo: u8, hi: u8) -> u64 {
+    let low7 = v & !HIGHS;
+    let ge_lo = low7 + (ONES * (0x80 - lo as u64));
+    let gt_hi = low7 + (ONES * (0x7f - hi as u64));
+    ge_lo & !gt_hi & !v & HIGHS
+}
@@ -0,0 +1,237 @@
+// The expected errors record num as Cow<str>, which requires alloc.
+#![cfg(feature = "alloc")]
+
+use strconv::{NumError, NumErrorCause};
+
+struct AtobTest {
+    input: &'static str,
+    output: bool,
+    err: Option<NumError<'static>>,
+}
+
+impl AtobTest {
+    const fn ok(input: &'static str, output: bool) -> Self {
+        Self {
+            input,
+            output,
+            err: None,
+        }
+    }
+
+    fn err(input: &'static str) -> Self {
+        let err = NumError {
+            func: "parse_bool",
+            num: input.into(),
+            err: NumErrorCause::InvalidSyntax,
+            invalid: None,
+        };
+
+        Self {
+            input,
+            output: false,
+            err: Some(err),
+        }
+    }
+}
+
+lazy_static::lazy_static! {
+  static ref ATOB_TESTS: Vec<AtobTest> = vec![
+    AtobTest::err(""),
+    AtobTest::err("asdf"),
+    AtobTest::ok("0", false),
+    AtobTest::ok("f", false),
+    AtobTest::ok("F", false),
+    AtobTest::ok("FALSE", false),
+    AtobTest::ok("false", false),
+    AtobTest::ok("False", false),
+    AtobTest::ok("1", true),
+    AtobTest::ok("t", true),
+    AtobTest::ok("T", true),
+    AtobTest::ok("TRUE", true),
+    AtobTest::ok("true", true),
+    AtobTest::ok("True", true),
+    AtobTest::err("tRUE"),
+    AtobTest::err("fAlse"),
+    AtobTest::err("yes"),
+    AtobTest::err("no"),
+    AtobTest::err("on"),
+    AtobTest::err("2"),
+    AtobTest::err("00"),
+    AtobTest::err(" true"),
+    AtobTest::err("true "),
+    AtobTest::err("truee"),
+    AtobTest::err("tru"),
+  ];
+}
+
+#[test]
+fn parse_bool() {
+    for c in ATOB_TESTS.iter() {
+        match strconv::parse_bool(c.input) {
+            Ok(got) => {
+                assert!(c.err.is_none(), "{}: expected error", c.input);
+                assert_eq!(got, c.output, "bad output for parse_bool({})", c.input);
+            }
+            Err(err) => {
+                let expect = c
+                    .err
+                    .as_ref()
+                    .unwrap_or_else(|| panic!("miss error: {}", c.input));
+                assert_eq!(&err, expect, "bad error for parse_bool({})", c.input);
+            }
+        }
+    }
+}
+
+#[test]
+fn parse_bool_bytes() {
+    for c in ATOB_TESTS.iter() {
+        let got = strconv::parse_bool_bytes(c.input.as_bytes());
+        assert_eq!(got, strconv::parse_bool(c.input), "{:?}", c.input);
+    }
+
+    // The invalid UTF-8 is rendered lossily in the errors.
+    let tests: &[(&[u8], &str)] = &[
+        (b"\xff", "\u{fffd}"),
+        (b"t\xff", "t\u{fffd}"),
+        (b"tru\xc3\x28", "tru\u{fffd}("),
+        (b"\xe4\xb8\xad", "\u{4e2d}"),
+    ];
+    for &(input, num) in tests {
+        let err = NumError {
+            func: "parse_bool",
+            num: num.into(),
+            err: NumErrorCause::InvalidSyntax,
+            invalid: None,
+        };
+        assert_eq!(strconv::parse_bool_bytes(input), Err(err), "{:?}", input);
+    }
+}
+
+const BOOL_STRINGS: &[(bool, &str)] = &[(true, "true"), (false, "false")];
+
+#[test]
+fn format_bool() {
+    for &(b, s) in BOOL_STRINGS {
+        assert_eq!(strconv::format_bool(b), s, "format_bool({})", b);
+        assert_eq!(strconv::parse_bool(s), Ok(b));
+    }
+}
+
+#[test]
+fn append_bool() {
+    let tests: &[(bool, &[u8], &[u8])] = &[
+        (true, b"foo ", b"foo true"),
+        (false, b"foo ", b"foo false"),
+        (true, b"", b"true"),
+    ];
+    for &(b, input, output) in tests {
+        let mut got = input.to_vec();
+        strconv::append_bool(&mut got, b);
+        assert_eq!(got, output, "append_bool({:?}, {})", input, b);
+    }
+}
+
+#[test]
+fn parse_bool_with() {
+    use strconv::BoolDialect;
+
+    let on_off = BoolDialect::new(&["on"], &["off"]);
+    let tests: &[(&str, BoolDialect, Option<bool>)] = &[
+        ("true", BoolDialect::GO, Some(true)),
+        ("F", BoolDialect::GO, Some(false)),
+        ("yes", BoolDialect::GO, None),
+        ("tRUE", BoolDialect::GO, None),
+        ("y", BoolDialect::YAML, Some(true)),
+        ("Yes", BoolDialect::YAML, Some(true)),
+        ("ON", BoolDialect::YAML, Some(true)),
+        ("n", BoolDialect::YAML, Some(false)),
+        ("NO", BoolDialect::YAML, Some(false)),
+        ("Off", BoolDialect::YAML, Some(false)),
+        ("FALSE", BoolDialect::YAML, Some(false)),
+        ("yEs", BoolDialect::YAML, None),
+        ("1", BoolDialect::YAML, None),
+        ("t", BoolDialect::YAML, None),
+        ("enable", BoolDialect::INI, Some(true)),
+        ("DISABLE", BoolDialect::INI, Some(false)),
+        ("Y", BoolDialect::INI, Some(true)),
+        ("0", BoolDialect::INI, Some(false)),
+        ("enabled", BoolDialect::INI, None),
+        ("TrUe", BoolDialect::ENV, Some(true)),
+        ("oFF", BoolDialect::ENV, Some(false)),
+        ("", BoolDialect::ENV, None),
+        ("y", BoolDialect::ENV, None),
+        ("on", on_off, Some(true)),
+        ("off", on_off, Some(false)),
+        ("ON", on_off, None),
+        ("ON", on_off.ignore_case(true), Some(true)),
+        ("ön", on_off.ignore_case(true), None),
+    ];
+
+    for &(input, dialect, output) in tests {
+        let got = strconv::parse_bool_with(input, dialect);
+        match output {
+            Some(v) => assert_eq!(got, Ok(v), "parse_bool_with({:?}, {:?})", input, dialect),
+            None => {
+                let err = NumError {
+                    func: "parse_bool_with",
+                    num: input.into(),
+                    err: NumErrorCause::InvalidSyntax,
+                    invalid: None,
+                };
+                assert_eq!(got, Err(err));
+            }
+        }
+    }
+
+    for &(input, dialect, _) in tests {
+        let got = strconv::parse_bool_with_bytes(input.as_bytes(), dialect);
+        assert_eq!(got, strconv::parse_bool_with(input, dialect), "{:?}", input);
+    }
+
+    let tests: &[(&[u8], &str)] = &[
+        (b"\xff", "\u{fffd}"),
+        (b"on\xff", "on\u{fffd}"),
+        (b"\xc3\x28", "\u{fffd}("),
+    ];
+    for &(input, num) in tests {
+        let err = NumError {
+            func: "parse_bool_with",
+            num: num.into(),
+            err: NumErrorCause::InvalidSyntax,
+            invalid: None,
+        };
+        let got = strconv::parse_bool_with_bytes(input, BoolDialect::ENV);
+        assert_eq!(got, Err(err), "{:?}", input);
+    }
+
+    // GO is the default, and behaves as parse_bool does but for the func.
+    assert_eq!(BoolDialect::default(), BoolDialect::GO);
+    for c in ATOB_TESTS.iter() {
+        let got = strconv::parse_bool_with(c.input, BoolDialect::default());
+        let want = strconv::parse_bool(c.input).map_err(|err| NumError {
+            func: "parse_bool_with",
+            ..err
+        });
+        assert_eq!(got, want, "parse_bool_with({:?})", c.input);
+    }
+}
+
+#[test]
+fn bool_dialect_display() {
+    use strconv::BoolDialect;
+
+    let err = strconv::parse_bool_with("maybe", BoolDialect::ENV).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "strconv.parse_bool_with: parsing 'maybe': invalid syntax"
+    );
+    assert_eq!(
+        BoolDialect::ENV.to_string(),
+        "true (1, true, yes, on) or false (0, false, no, off), ignoring case"
+    );
+    assert_eq!(
+        BoolDialect::new(&["x"], &[]).to_string(),
+        "true (x) or false ()"
+    );
+}
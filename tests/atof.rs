@@ -0,0 +1,679 @@
+use strconv::NumErrorCause;
+
+/// Inputs parsed as std does, which rounds correctly, once any underscores
+/// are dropped.
+const ATOF64_OK: &[&str] = &[
+    "1",
+    "+1",
+    "1e23",
+    "1E23",
+    "100000000000000000000000",
+    "1e-100",
+    "123456700",
+    "99999999999999974834176",
+    "100000000000000000000001",
+    "100000000000000008388608",
+    "100000000000000016777215",
+    "100000000000000016777216",
+    "-1",
+    "-0.1",
+    "-0",
+    "1e-20",
+    "625e-3",
+    ".5",
+    "5.",
+    "-.5e1",
+    "0",
+    "0e0",
+    "-0e0",
+    "+0e0",
+    "0e-0",
+    "0e+0",
+    "0e+01234567890123456789",
+    "0.00e-01234567890123456789",
+    "-0e+01234567890123456789",
+    "-0.00e-01234567890123456789",
+    "1.7976931348623157e308",
+    "-1.7976931348623157e308",
+    "1.7976931348623158e308",
+    "1e308",
+    "1e-305",
+    "1e-306",
+    "1e-320",
+    "1e-350",
+    "1e-400000",
+    "1e-4294967296",
+    "1e-18446744073709551616",
+    "2.2250738585072012e-308",
+    "2.2250738585072011e-308",
+    "4.630813248087435e+307",
+    "22.222222222222222",
+    "1.00000000000000011102230246251565404236316680908203125",
+    "1.00000000000000011102230246251565404236316680908203124",
+    "1.00000000000000011102230246251565404236316680908203126",
+    "1.000000000000000111022302462515654042363166809082031251",
+    "4.940656458412465441765687928682213723651e-324",
+    "4.940656458412465441765687928682213723650e-324",
+    "2.470328229206232720882e-324",
+    "2.470328229206232720883e-324",
+    "123456789012345678901234567890",
+    "0.000000000000000000000000000001234567890123456789",
+    "9007199254740993",
+    "9007199254740993.0000000000000000000000000000001",
+    "1_23.50_0_0e+1_2",
+    "1_000_000",
+    "0_1",
+    "1e1_0",
+];
+
+/// Inputs and the offsets of their invalid characters.
+const ATOF_SYNTAX_ERRORS: &[(&str, Option<usize>)] = &[
+    ("", None),
+    ("-", None),
+    ("+", None),
+    (".", None),
+    ("-.", None),
+    ("1e", None),
+    ("1e+", None),
+    ("1e-", None),
+    ("x", Some(0)),
+    ("1x", Some(1)),
+    ("1.1.", Some(3)),
+    ("..5", Some(1)),
+    (".e1", Some(1)),
+    ("1e+x", Some(3)),
+    ("1ex", Some(2)),
+    ("1 ", Some(1)),
+    (" 1", Some(0)),
+    ("1.5\u{e9}", Some(3)),
+    ("0x", Some(1)),
+    ("0x10", None),
+    ("0x1e2", None),
+    ("0x1.8e3", None),
+    ("-0x1", None),
+    ("0x1p", None),
+    ("0x1p+", None),
+    ("0x1g", Some(3)),
+    ("0x1.8.p1", Some(5)),
+    ("0xp1", Some(2)),
+    ("0x.p1", Some(3)),
+    ("0x1px", Some(4)),
+    ("0x1e1", None),
+    ("0b1p0", Some(1)),
+    ("1p2", Some(1)),
+    ("0x1_p0", Some(3)),
+    ("0x1p_0", Some(4)),
+    ("0x__1p0", Some(3)),
+    ("0x1p1_", Some(5)),
+    ("infinit", Some(3)),
+    ("+infinit", Some(4)),
+    ("-INFIN", Some(4)),
+    ("infinitx", Some(7)),
+    ("infinity0", Some(8)),
+    ("infx", Some(3)),
+    ("Infinite", Some(7)),
+    ("inx", Some(2)),
+    ("in", None),
+    ("i", None),
+    ("+", None),
+    ("-nan", Some(1)),
+    ("+NaN", Some(1)),
+    ("nanx", Some(3)),
+    ("nax", Some(2)),
+    ("na", None),
+    ("_inf", Some(1)),
+    ("inf_", Some(3)),
+    ("-_123.5e+12", Some(1)),
+    ("+_123.5e+12", Some(1)),
+    ("_123.5e+12", Some(0)),
+    ("1__23.5e+12", Some(2)),
+    ("123_.5e+12", Some(3)),
+    ("123._5e+12", Some(4)),
+    ("123.5_e+12", Some(5)),
+    ("123.5__0e+12", Some(6)),
+    ("123.5e_+12", Some(6)),
+    ("123.5e+_12", Some(7)),
+    ("123.5e_-12", Some(6)),
+    ("123.5e-_12", Some(7)),
+    ("123.5e+1__2", Some(9)),
+    ("123.5e+12_", Some(9)),
+    ("1_x", Some(1)),
+];
+
+#[test]
+fn parse_float64() {
+    for &input in ATOF64_OK {
+        let want: f64 = input.replace('_', "").parse().unwrap();
+        let got = strconv::parse_float(input, 64);
+        assert_eq!(
+            got.map(f64::to_bits),
+            Ok(want.to_bits()),
+            "parse_float({}, 64)",
+            input
+        );
+    }
+}
+
+#[test]
+fn parse_float32() {
+    let tests = [
+        "1",
+        "0.1",
+        "3.14159",
+        "-0",
+        "1e-38",
+        "1e-45",
+        "7e-46",
+        "7.1e-46",
+        "1.17549435e-38",
+        "1.000000059604644775390625",
+        "1.000000059604644775390624",
+        "1.000000059604644775390626",
+        // Rounds to a halfway f64, which rounds to 1 as f32.
+        "1.00000005960464477550",
+        "1.0000000596046447755",
+        "340282346638528859811704183484516925440",
+        "3.4028235e38",
+        "-3.4028235e38",
+        "3.40282356779733661637539395458142568447e38",
+        "16777217",
+        "16777217.000000001",
+        "1e10",
+        "1.5e17",
+        "1.5e18",
+    ];
+
+    for input in tests {
+        let want: f32 = input.parse().unwrap();
+        let got = strconv::parse_float(input, 32);
+        assert_eq!(
+            got.map(f64::to_bits),
+            Ok(f64::from(want).to_bits()),
+            "parse_float({}, 32)",
+            input
+        );
+    }
+}
+
+#[test]
+fn parse_float_range() {
+    let tests = [
+        ("1e309", 64, f64::INFINITY),
+        ("-1e309", 64, f64::NEG_INFINITY),
+        ("2e308", 64, f64::INFINITY),
+        ("1e310", 64, f64::INFINITY),
+        ("1e400", 64, f64::INFINITY),
+        ("1e400000", 64, f64::INFINITY),
+        ("1e+4294967296", 64, f64::INFINITY),
+        ("1e+18446744073709551616", 64, f64::INFINITY),
+        ("1.797693134862315808e308", 64, f64::INFINITY),
+        ("-1.797693134862315808e308", 64, f64::NEG_INFINITY),
+        ("1.7976931348623159e308", 64, f64::INFINITY),
+        ("3.4028236e38", 32, f64::INFINITY),
+        ("-3.4028236e38", 32, f64::NEG_INFINITY),
+        ("3.40282357e38", 32, f64::INFINITY),
+        ("1e39", 32, f64::INFINITY),
+        ("1e309", 32, f64::INFINITY),
+    ];
+
+    for (input, bit_size, bound_hint) in tests {
+        let err = strconv::parse_float(input, bit_size).unwrap_err();
+        assert_eq!(err.func, "parse_float");
+        assert_eq!(err.num, input);
+        assert_eq!(
+            err.err.bound_hint(),
+            Some(bound_hint),
+            "parse_float({}, {})",
+            input,
+            bit_size
+        );
+        assert_eq!(err.invalid, None);
+    }
+
+    let err = strconv::parse_float("1e309", 64).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "strconv.parse_float: parsing '1e309': value out of range: inf"
+    );
+}
+
+#[test]
+fn parse_float_syntax() {
+    for &(input, offset) in ATOF_SYNTAX_ERRORS {
+        for bit_size in [32, 64] {
+            let err = strconv::parse_float(input, bit_size).unwrap_err();
+            assert_eq!(err.func, "parse_float");
+            assert_eq!(err.err, NumErrorCause::InvalidSyntax, "{:?}", input);
+            assert_eq!(
+                err.invalid.map(|v| v.offset),
+                offset,
+                "parse_float({:?}, {})",
+                input,
+                bit_size
+            );
+        }
+    }
+
+    let err = strconv::parse_float_bytes(b"1.5\xff", 64).unwrap_err();
+    assert_eq!(err.invalid.map(|v| v.ch), Some(char::REPLACEMENT_CHARACTER));
+}
+
+#[test]
+fn parse_float_bit_size() {
+    for bit_size in [0, 8, 16, 63, 65, 128] {
+        let err = strconv::parse_float("1", bit_size).unwrap_err();
+        assert_eq!(err.err, NumErrorCause::InvalidBitSize(bit_size));
+    }
+}
+
+#[test]
+fn parse_float_special() {
+    let tests = [
+        ("inf", f64::INFINITY),
+        ("+Inf", f64::INFINITY),
+        ("-inf", f64::NEG_INFINITY),
+        ("infinity", f64::INFINITY),
+        ("+INFINITY", f64::INFINITY),
+        ("-InFiNiTy", f64::NEG_INFINITY),
+        ("nan", f64::NAN),
+        ("NaN", f64::NAN),
+        ("nAN", f64::NAN),
+    ];
+
+    for (input, want) in tests {
+        for bit_size in [32, 64] {
+            let got = strconv::parse_float(input, bit_size).unwrap();
+            if want.is_nan() {
+                assert!(got.is_nan(), "parse_float({:?}, {})", input, bit_size);
+            } else {
+                assert_eq!(got, want, "parse_float({:?}, {})", input, bit_size);
+            }
+        }
+    }
+}
+
+#[test]
+fn parse_float_prefix() {
+    let tests = [
+        ("1", 1.0, 1),
+        ("1.5e3ms", 1500.0, 5),
+        ("-0.25, 1", -0.25, 5),
+        ("0x1.8p1x", 3.0, 7),
+        ("1_000 km", 1000.0, 5),
+        ("inf", f64::INFINITY, 3),
+        ("-Inf,", f64::NEG_INFINITY, 4),
+        ("infinity and beyond", f64::INFINITY, 8),
+        ("+infinit", f64::INFINITY, 4),
+        ("infx", f64::INFINITY, 3),
+        ("infinity0", f64::INFINITY, 8),
+    ];
+    for (input, want, n) in tests {
+        assert_eq!(
+            strconv::parse_float_prefix(input, 64),
+            Ok((want, n)),
+            "parse_float_prefix({:?})",
+            input
+        );
+    }
+
+    for input in ["nan", "NaN,", "nanx"] {
+        let (got, n) = strconv::parse_float_prefix(input, 32).unwrap();
+        assert!(got.is_nan(), "parse_float_prefix({:?})", input);
+        assert_eq!(n, 3, "parse_float_prefix({:?})", input);
+    }
+
+    let tests = [
+        ("", None),
+        ("x", Some(0)),
+        ("-nan", Some(1)),
+        ("inx", Some(2)),
+        ("in", None),
+        ("1e+x", Some(3)),
+        ("0x1.8", None),
+        ("0x1x", Some(3)),
+    ];
+    for (input, offset) in tests {
+        let err = strconv::parse_float_prefix(input, 64).unwrap_err();
+        assert_eq!(err.func, "parse_float_prefix");
+        assert_eq!(err.err, NumErrorCause::InvalidSyntax, "{:?}", input);
+        assert_eq!(
+            err.invalid.map(|v| v.offset),
+            offset,
+            "parse_float_prefix({:?})",
+            input
+        );
+    }
+
+    let err = strconv::parse_float_prefix("1e39,", 32).unwrap_err();
+    assert_eq!(err.err, NumErrorCause::OutOfRangeFloat { negative: false });
+    let err = strconv::parse_float_prefix("1", 16).unwrap_err();
+    assert_eq!(err.err, NumErrorCause::InvalidBitSize(16));
+}
+
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+}
+
+/// Checks parse_float against std for the input, which std accepts.
+fn check_std(input: &str) {
+    let want: f64 = input.parse().unwrap();
+    let got = strconv::parse_float(input, 64);
+    if want.is_infinite() {
+        let err = got.unwrap_err().err;
+        assert_eq!(
+            err,
+            NumErrorCause::OutOfRangeFloat {
+                negative: want < 0.0
+            }
+        );
+    } else {
+        let got = got.map(f64::to_bits);
+        assert_eq!(got, Ok(want.to_bits()), "parse_float({}, 64)", input);
+    }
+
+    let want = f64::from(input.parse::<f32>().unwrap());
+    let got = strconv::parse_float(input, 32);
+    if want.is_infinite() {
+        let err = got.unwrap_err().err;
+        assert_eq!(
+            err,
+            NumErrorCause::OutOfRangeFloat {
+                negative: want < 0.0
+            }
+        );
+    } else {
+        let got = got.map(f64::to_bits);
+        assert_eq!(got, Ok(want.to_bits()), "parse_float({}, 32)", input);
+    }
+}
+
+#[test]
+fn parse_float_random() {
+    let mut rng = XorShift(0x2545_f491_4f6c_dd1d);
+    for _ in 0..20000 {
+        let mut s = String::new();
+        if rng.below(2) == 0 {
+            s.push('-');
+        }
+
+        let digits = 1 + rng.below(30) as usize;
+        let dot = rng.below(digits as u64 + 2) as usize;
+        for i in 0..digits {
+            if i == dot {
+                s.push('.');
+            }
+            // Favor runs of 0 and 9, which are near the rounding boundaries.
+            let d = match rng.below(4) {
+                0 => 0,
+                1 => 9,
+                _ => rng.below(10),
+            };
+            s.push(char::from(b'0' + d as u8));
+        }
+
+        if rng.below(4) != 0 {
+            let exp = rng.below(720) as i64 - 360;
+            s.push_str(&format!("e{}", exp));
+        }
+
+        check_std(&s);
+    }
+}
+
+/// Returns the decimal digits of m * 2^e (for e >= 0) or m * 5^-e (for
+/// e < 0), whose value is m * 2^e scaled by 10^-e in the latter case.
+fn scaled_digits(m: u64, e: i32) -> Vec<u8> {
+    let mut digits: Vec<u8> = m.to_string().bytes().map(|c| c - b'0').collect();
+    let (factor, count) = if e >= 0 { (2, e) } else { (5, -e) };
+    for _ in 0..count {
+        let mut carry = 0;
+        for d in digits.iter_mut().rev() {
+            let v = *d * factor + carry;
+            *d = v % 10;
+            carry = v / 10;
+        }
+        if carry > 0 {
+            digits.insert(0, carry);
+        }
+    }
+    digits
+}
+
+/// Renders digits * 10^exp, followed by the given tail of digits.
+fn render(digits: &[u8], tail: &str, exp: i32) -> String {
+    let mut s: String = digits.iter().map(|&d| char::from(b'0' + d)).collect();
+    s.push_str(tail);
+    format!("{}e{}", s, exp - tail.len() as i32)
+}
+
+/// Checks the exact halfway point between the float of mantissa m and
+/// exponent e and its successor, along with the numbers just around it.
+fn check_halfway(m: u64, e: i32) {
+    // (2m+1) * 2^(e-1)
+    let digits = scaled_digits(2 * m + 1, e - 1);
+    let exp = (e - 1).min(0);
+
+    check_std(&render(&digits, "", exp));
+    check_std(&render(&digits, "0000000000000000000001", exp));
+
+    // Just below, by decrementing the digits followed by nines.
+    let mut below = digits.clone();
+    for d in below.iter_mut().rev() {
+        if *d > 0 {
+            *d -= 1;
+            break;
+        }
+        *d = 9;
+    }
+    check_std(&render(&below, "9999999999999999999999", exp));
+}
+
+#[test]
+fn parse_float_halfway() {
+    let mut rng = XorShift(0x9e37_79b9_7f4a_7c15);
+
+    // Around f64 values at the extremes and random ones.
+    let mut values = vec![
+        0,
+        1,
+        2,
+        (1 << 52) - 1,
+        1 << 52,
+        (1 << 52) + 1,
+        0x7fef_ffff_ffff_fffe,
+        0x7fef_ffff_ffff_ffff,
+        1.0f64.to_bits(),
+        1.0f64.to_bits() - 1,
+    ];
+    for _ in 0..300 {
+        values.push(rng.below(0x7ff0_0000_0000_0000));
+    }
+    for bits in values {
+        let biased = (bits >> 52) as i32;
+        let frac = bits & ((1 << 52) - 1);
+        let (m, e) = match biased {
+            0 => (frac, -1074),
+            _ => (frac | (1 << 52), biased - 1075),
+        };
+        check_halfway(m, e);
+    }
+
+    // Around f32 values, which are halfway between f32 values but not
+    // between f64 values.
+    let mut values = vec![0, 1, (1 << 23) - 1, 1 << 23, 0x7f7f_fffe, 0x7f7f_ffff];
+    for _ in 0..300 {
+        values.push(rng.below(0x7f80_0000));
+    }
+    for bits in values {
+        let biased = (bits >> 23) as i32;
+        let frac = bits & ((1 << 23) - 1);
+        let (m, e) = match biased {
+            0 => (frac, -149),
+            _ => (frac | (1 << 23), biased - 150),
+        };
+        check_halfway(m, e);
+    }
+}
+
+#[test]
+fn parse_float_hex() {
+    let min_sub64 = f64::from_bits(1);
+    let tests = [
+        ("0x1p0", 64, 1.0),
+        ("0x1p1", 64, 2.0),
+        ("0x1p-1", 64, 0.5),
+        ("0x1ep-1", 64, 15.0),
+        ("-0x1ep-1", 64, -15.0),
+        ("-0x1_ep-1", 64, -15.0),
+        ("0x_1p0", 64, 1.0),
+        ("0X1P+0", 64, 1.0),
+        ("0x1p-200", 64, 6.223015277861142e-61),
+        ("0x1p200", 64, 1.6069380442589903e+60),
+        ("0x1fFe2.p0", 64, 131042.0),
+        ("0x1fFe2.P0", 64, 131042.0),
+        ("-0x2p3", 64, -16.0),
+        ("0x0.fp4", 64, 15.0),
+        ("0x0.fp0", 64, 0.9375),
+        ("0x1.8p3", 64, 12.0),
+        ("0X.8P-2", 64, 0.125),
+        ("0x.8p1", 64, 1.0),
+        ("0x0p0", 64, 0.0),
+        ("-0x0p0", 64, -0.0),
+        ("0x0p99999999", 64, 0.0),
+        ("0x00000000000000000000001p0", 64, 1.0),
+        ("0x1000000000000000000000000p-96", 64, 1.0),
+        ("0x.000000000000000000000001p96", 64, 1.0),
+        ("0x1p1_0", 64, 1024.0),
+        // f64 boundaries
+        ("0x1.fffffffffffffp1023", 64, f64::MAX),
+        ("0x1.fffffffffffff7fffp1023", 64, f64::MAX),
+        ("-0x1.fffffffffffff7fffp1023", 64, -f64::MAX),
+        ("0x1p-1022", 64, f64::MIN_POSITIVE),
+        ("0x0.fffffffffffffp-1022", 64, f64::MIN_POSITIVE - min_sub64),
+        ("0x1.ffffffffffffffp-1023", 64, f64::MIN_POSITIVE),
+        ("0x1p-1074", 64, min_sub64),
+        ("0x1.8p-1074", 64, 2.0 * min_sub64),
+        ("0x1p-1075", 64, 0.0),
+        ("0x1.0000000000001p-1075", 64, min_sub64),
+        ("0x1.00000000000000000000001p-1075", 64, min_sub64),
+        ("0x1p-2000", 64, 0.0),
+        // f64 rounding: halfway to even, and above halfway
+        ("0x1.00000000000008p0", 64, 1.0),
+        ("0x1.00000000000007ffp0", 64, 1.0),
+        ("0x1.000000000000080000000000001p0", 64, 1.0 + f64::EPSILON),
+        ("0x1.00000000000018p0", 64, 1.0 + 2.0 * f64::EPSILON),
+        ("0x1.00000000000017ffffffffffffp0", 64, 1.0 + f64::EPSILON),
+        ("0x1.fffffffffffff8p0", 64, 2.0),
+        ("0x1fffffffffffff8p-56", 64, 2.0),
+        ("0x20000000000001p0", 64, 9007199254740992.0),
+        ("0x20000000000003p0", 64, 9007199254740996.0),
+        ("0xffffffffffffffffffffp0", 64, 1208925819614629174706176.0),
+        // f32 boundaries
+        ("0x1.fffffep127", 32, f32::MAX as f64),
+        ("0x1.fffffefffp127", 32, f32::MAX as f64),
+        ("-0x1.fffffefffp127", 32, -f32::MAX as f64),
+        ("0x1p-126", 32, f32::MIN_POSITIVE as f64),
+        ("0x1p-149", 32, f32::from_bits(1) as f64),
+        ("0x1.8p-149", 32, f32::from_bits(2) as f64),
+        ("0x1p-150", 32, 0.0),
+        ("0x1.000002p-150", 32, f32::from_bits(1) as f64),
+        // f32 rounding, which isn't by way of f64
+        ("0x1.000001p0", 32, 1.0),
+        ("0x1.0000010000000000001p0", 32, (1.0 + f32::EPSILON) as f64),
+        ("0x1.000003p0", 32, (1.0 + 2.0 * f32::EPSILON) as f64),
+        ("0x1.0000008p0", 32, 1.0),
+        ("0x1.0000018p0", 32, (1.0 + f32::EPSILON) as f64),
+        ("0x1.fffffffffffffp1023", 32, f64::INFINITY),
+    ];
+
+    for (input, bit_size, want) in tests {
+        let got = strconv::parse_float(input, bit_size);
+        if want.is_infinite() {
+            let err = got.unwrap_err();
+            assert_eq!(
+                err.err,
+                NumErrorCause::OutOfRangeFloat {
+                    negative: want < 0.0
+                }
+            );
+        } else {
+            assert_eq!(
+                got.map(f64::to_bits),
+                Ok(want.to_bits()),
+                "parse_float({}, {})",
+                input,
+                bit_size
+            );
+        }
+    }
+}
+
+#[test]
+fn parse_float_hex_range() {
+    let tests = [
+        ("0x1p1024", 64, f64::INFINITY),
+        ("-0x1p1024", 64, f64::NEG_INFINITY),
+        ("0x1.fffffffffffff8p1023", 64, f64::INFINITY),
+        ("-0x1.fffffffffffff8p1023", 64, f64::NEG_INFINITY),
+        ("0x1p99999999", 64, f64::INFINITY),
+        ("0x1p128", 32, f64::INFINITY),
+        ("0x1.ffffffp127", 32, f64::INFINITY),
+        ("-0x1.ffffffp127", 32, f64::NEG_INFINITY),
+    ];
+
+    for (input, bit_size, bound_hint) in tests {
+        let err = strconv::parse_float(input, bit_size).unwrap_err();
+        assert_eq!(
+            err.err.bound_hint(),
+            Some(bound_hint),
+            "parse_float({}, {})",
+            input,
+            bit_size
+        );
+    }
+}
+
+/// Renders m * 2^exp as a hexadecimal float, with the point put ahead of
+/// the last `frac` digits.
+fn hex_float(m: u128, exp: i32, frac: usize) -> String {
+    let digits = format!("{:x}", m);
+    let frac = frac.min(digits.len());
+    let (int, fract) = digits.split_at(digits.len() - frac);
+    format!("0x{}.{}p{}", int, fract, exp + 4 * frac as i32)
+}
+
+#[test]
+fn parse_float_hex_random() {
+    let mut rng = XorShift(0xd1b5_4a32_d192_ed03);
+    for _ in 0..20000 {
+        // Up to 96 bits, which is beyond the 16 digits kept.
+        let bits = 1 + rng.below(96) as u32;
+        let m = (u128::from(rng.next()) << 64 | u128::from(rng.next())) >> (128 - bits);
+        let frac = rng.below(8) as usize;
+
+        // Casts round to nearest even, and scaling by 2^exp is exact in the
+        // normal range.
+        let exp = rng.below(1800) as i32 - 900 - bits as i32;
+        let want = m as f64 * 2f64.powi(exp);
+        let input = hex_float(m, exp, frac);
+        let got = strconv::parse_float(&input, 64);
+        assert_eq!(got, Ok(want), "parse_float({}, 64)", input);
+
+        let exp = rng.below(220) as i32 - 110 - bits as i32;
+        let want = (m as f32) as f64 * 2f64.powi(exp);
+        let input = hex_float(m, exp, frac);
+        let got = strconv::parse_float(&input, 32);
+        assert_eq!(got, Ok(want), "parse_float({}, 32)", input);
+    }
+}
@@ -1,4 +1,9 @@
-use strconv::{NumError, NumErrorCause};
+// The expected errors record num as Cow<str>, which requires alloc.
+#![cfg(feature = "alloc")]
+
+use std::borrow::Cow;
+
+use strconv::{InvalidChar, NumError, NumErrorCause};
 
 //type OpaqueError = Box<dyn std::error::Error + Sync + Send + 'static>;
 
@@ -48,7 +53,7 @@ lazy_static::lazy_static! {
     ParseInt64Test::ok("-9223372036854775807", -i64::MAX),
     ParseInt64Test::err_signed("9223372036854775808", i64::MAX, NumErrorCause::OutOfRangeSigned { bound_hint: i64::MAX }),
     ParseInt64Test::ok("-9223372036854775808", -1 << 63),
-    ParseInt64Test::err_signed("9223372036854775809", 1<<63 - 1, NumErrorCause::OutOfRangeSigned { bound_hint: i64::MAX }),
+    ParseInt64Test::err_signed("9223372036854775809", i64::MAX, NumErrorCause::OutOfRangeSigned { bound_hint: i64::MAX }),
     ParseInt64Test::err_signed("-9223372036854775809", -1 << 63, NumErrorCause::OutOfRangeSigned { bound_hint: i64::MIN }),
     ParseInt64Test::err_signed("-1_2_3_4_5", 0, NumErrorCause::InvalidSyntax), // base=10 so no underscores allowed
     ParseInt64Test::err_signed("-_12345", 0, NumErrorCause::InvalidSyntax),
@@ -92,7 +97,7 @@ lazy_static::lazy_static! {
     ParseInt64BaseTest::ok("1010", 2, 10),
     ParseInt64BaseTest::ok("1000000000000000", 2, 1 << 15),
     ParseInt64BaseTest::ok("111111111111111111111111111111111111111111111111111111111111111", 2, i64::MAX),
-    ParseInt64BaseTest::err_signed("1000000000000000000000000000000000000000000000000000000000000000", 2, 1<<63 - 1, NumErrorCause::OutOfRangeSigned{bound_hint:i64::MAX}),
+    ParseInt64BaseTest::err_signed("1000000000000000000000000000000000000000000000000000000000000000", 2, i64::MAX, NumErrorCause::OutOfRangeSigned{bound_hint:i64::MAX}),
     ParseInt64BaseTest::ok("-1000000000000000000000000000000000000000000000000000000000000000", 2, -1 << 63),
     ParseInt64BaseTest::err_signed("-1000000000000000000000000000000000000000000000000000000000000001", 2, -1 << 63, NumErrorCause::OutOfRangeSigned{bound_hint:i64::MIN}),
 
@@ -109,6 +114,8 @@ lazy_static::lazy_static! {
     // underscores
     ParseInt64BaseTest::ok("-0x_1_2_3_4_5", 0, -0x12345),
     ParseInt64BaseTest::ok("0x_1_2_3_4_5", 0, 0x12345),
+    ParseInt64BaseTest::ok("0xA_B", 0, 0xab),
+    ParseInt64BaseTest::ok("-0XA_b_C", 0, -0xabc),
     ParseInt64BaseTest::err_signed("-_0x12345", 0, 0, NumErrorCause::InvalidSyntax),
     ParseInt64BaseTest::err_signed("_-0x12345", 0, 0, NumErrorCause::InvalidSyntax),
     ParseInt64BaseTest::err_signed("_0x12345", 0, 0, NumErrorCause::InvalidSyntax),
@@ -193,6 +200,8 @@ lazy_static::lazy_static! {
     ParseUint64BaseTest::err("12345_", 10, 0, NumErrorCause::InvalidSyntax),
 
     ParseUint64BaseTest::ok("0x_1_2_3_4_5", 0, 0x12345), // base 0 => 16
+    ParseUint64BaseTest::ok("0xA_B", 0, 0xab),
+    ParseUint64BaseTest::ok("0X_F_f", 0, 0xff),
     ParseUint64BaseTest::err("_0x12345", 0, 0, NumErrorCause::InvalidSyntax),
     ParseUint64BaseTest::err("0x__12345", 0, 0, NumErrorCause::InvalidSyntax),
     ParseUint64BaseTest::err("0x1__2345", 0, 0, NumErrorCause::InvalidSyntax),
@@ -237,6 +246,46 @@ lazy_static::lazy_static! {
     ParseUint64BaseTest::err("101_", 2, 0, NumErrorCause::InvalidSyntax),
   ];
 
+  static ref PARSE_UINT128_BASE_TESTS: Vec<ParseUint128BaseTest> = vec![
+    ParseUint128BaseTest::err128("", 0, 0, NumErrorCause::InvalidSyntax),
+    ParseUint128BaseTest::ok("0", 0, 0),
+    ParseUint128BaseTest::ok("18446744073709551615", 0, u64::MAX as u128),
+    ParseUint128BaseTest::ok("18446744073709551616", 0, 1 << 64),
+    ParseUint128BaseTest::ok("340282366920938463463374607431768211455", 0, u128::MAX),
+    ParseUint128BaseTest::err128("340282366920938463463374607431768211456", 0, u128::MAX, NumErrorCause::OutOfRangeUnsigned128{bound_hint: u128::MAX}),
+    ParseUint128BaseTest::err128("3402823669209384634633746074317682114550", 0, u128::MAX, NumErrorCause::OutOfRangeUnsigned128{bound_hint: u128::MAX}),
+    ParseUint128BaseTest::ok("0xffffffffffffffffffffffffffffffff", 0, u128::MAX),
+    ParseUint128BaseTest::ok("0XFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF", 0, u128::MAX),
+    ParseUint128BaseTest::err128("0x100000000000000000000000000000000", 0, u128::MAX, NumErrorCause::OutOfRangeUnsigned128{bound_hint: u128::MAX}),
+    ParseUint128BaseTest::ok("0o3777777777777777777777777777777777777777777", 0, u128::MAX),
+    ParseUint128BaseTest::err128("0o4000000000000000000000000000000000000000000", 0, u128::MAX, NumErrorCause::OutOfRangeUnsigned128{bound_hint: u128::MAX}),
+    ParseUint128BaseTest::ok("1_000_000_000_000_000_000_000", 0, 1_000_000_000_000_000_000_000),
+    ParseUint128BaseTest::err128("1__000", 0, 0, NumErrorCause::InvalidSyntax),
+    ParseUint128BaseTest::err128("1_000", 10, 0, NumErrorCause::InvalidSyntax),
+    ParseUint128BaseTest::err128("-1", 0, 0, NumErrorCause::InvalidSyntax),
+    ParseUint128BaseTest::ok("20010db8000000000000000000000001", 16, 0x2001_0db8_0000_0000_0000_0000_0000_0001),
+    ParseUint128BaseTest::ok("f5lxx1zz5pnorynqglhzmsp33", 36, u128::MAX),
+    ParseUint128BaseTest::err128("f5lxx1zz5pnorynqglhzmsp34", 36, u128::MAX, NumErrorCause::OutOfRangeUnsigned128{bound_hint: u128::MAX}),
+    ParseUint128BaseTest::err128("1", 37, 0, NumErrorCause::InvalidBase(37)),
+  ];
+
+  static ref PARSE_INT128_BASE_TESTS: Vec<ParseInt128BaseTest> = vec![
+    ParseInt128BaseTest::err_signed128("", 0, 0, NumErrorCause::InvalidSyntax),
+    ParseInt128BaseTest::ok("-0", 0, 0),
+    ParseInt128BaseTest::ok("+1", 0, 1),
+    ParseInt128BaseTest::ok("-9223372036854775809", 0, i64::MIN as i128 - 1),
+    ParseInt128BaseTest::ok("170141183460469231731687303715884105727", 0, i128::MAX),
+    ParseInt128BaseTest::err_signed128("170141183460469231731687303715884105728", 0, i128::MAX, NumErrorCause::OutOfRangeSigned128{bound_hint: i128::MAX}),
+    ParseInt128BaseTest::ok("-170141183460469231731687303715884105728", 0, i128::MIN),
+    ParseInt128BaseTest::err_signed128("-170141183460469231731687303715884105729", 0, i128::MIN, NumErrorCause::OutOfRangeSigned128{bound_hint: i128::MIN}),
+    ParseInt128BaseTest::err_signed128("-340282366920938463463374607431768211456", 0, i128::MIN, NumErrorCause::OutOfRangeSigned128{bound_hint: i128::MIN}),
+    ParseInt128BaseTest::ok("-0x8000_0000_0000_0000_0000_0000_0000_0000", 0, i128::MIN),
+    ParseInt128BaseTest::ok("0x7fff_ffff_ffff_ffff_ffff_ffff_ffff_ffff", 0, i128::MAX),
+    ParseInt128BaseTest::err_signed128("0x_8000_0000_0000_0000_0000_0000_0000_0000", 0, i128::MAX, NumErrorCause::OutOfRangeSigned128{bound_hint: i128::MAX}),
+    ParseInt128BaseTest::err_signed128("-_0x1", 0, 0, NumErrorCause::InvalidSyntax),
+    ParseInt128BaseTest::err_signed128("0x-1", 0, 0, NumErrorCause::InvalidSyntax),
+  ];
+
   static ref PARSE_UINT64_TESTS: Vec<ParseUint64Test> = vec![
     ParseUint64Test::err ("", 0, NumErrorCause::InvalidSyntax),
     ParseUint64Test::ok("0", 0),
@@ -258,21 +307,34 @@ lazy_static::lazy_static! {
   ];
 }
 
+/// Drops the location of the invalid character, which is covered by
+/// [invalid_char] on its own.
+fn unlocated(err: NumError) -> NumError {
+    NumError {
+        invalid: None,
+        ..err
+    }
+}
+
 struct ParseBaseTest<T> {
     input: &'static str,
     base: u8,
     output: T,
-    err: Option<NumError>,
+    err: Option<NumError<'static>>,
 }
 
 type ParseInt64BaseTest = ParseBaseTest<i64>;
 
 type ParseUint64BaseTest = ParseBaseTest<u64>;
 
+type ParseInt128BaseTest = ParseBaseTest<i128>;
+
+type ParseUint128BaseTest = ParseBaseTest<u128>;
+
 struct ParseTest<T> {
     input: &'static str,
     output: T,
-    err: Option<NumError>,
+    err: Option<NumError<'static>>,
 }
 
 type ParseInt32Test = ParseTest<i32>;
@@ -284,11 +346,44 @@ type ParseUint32Test = ParseTest<u32>;
 type ParseUint64Test = ParseTest<u64>;
 
 impl<T> ParseBaseTest<T> {
+    fn err128(input: &'static str, base: u8, output: T, err: NumErrorCause) -> Self {
+        let err = NumError {
+            func: "parse_uint128",
+            num: input.into(),
+            err,
+            invalid: None,
+        };
+
+        Self {
+            input,
+            base,
+            output,
+            err: Some(err),
+        }
+    }
+
+    fn err_signed128(input: &'static str, base: u8, output: T, err: NumErrorCause) -> Self {
+        let err = NumError {
+            func: "parse_int128",
+            num: input.into(),
+            err,
+            invalid: None,
+        };
+
+        Self {
+            input,
+            base,
+            output,
+            err: Some(err),
+        }
+    }
+
     fn err(input: &'static str, base: u8, output: T, err: NumErrorCause) -> Self {
         let err = NumError {
-            func: "parse_uint".to_string(),
-            num: input.to_string(),
+            func: "parse_uint",
+            num: input.into(),
             err,
+            invalid: None,
         };
 
         Self {
@@ -301,9 +396,10 @@ impl<T> ParseBaseTest<T> {
 
     fn err_signed(input: &'static str, base: u8, output: T, err: NumErrorCause) -> Self {
         let err = NumError {
-            func: "parse_int".to_string(),
-            num: input.to_string(),
+            func: "parse_int",
+            num: input.into(),
             err,
+            invalid: None,
         };
 
         Self {
@@ -327,9 +423,10 @@ impl<T> ParseBaseTest<T> {
 impl<T> ParseTest<T> {
     fn err_signed(input: &'static str, output: T, err: NumErrorCause) -> Self {
         let err = NumError {
-            func: "parse_int".to_string(),
-            num: input.to_string(),
+            func: "parse_int",
+            num: input.into(),
             err,
+            invalid: None,
         };
 
         Self {
@@ -349,9 +446,10 @@ impl<T> ParseTest<T> {
 
     fn err(input: &'static str, output: T, err: NumErrorCause) -> Self {
         let err = NumError {
-            func: "parse_uint".to_string(),
-            num: input.to_string(),
+            func: "parse_uint",
+            num: input.into(),
             err,
+            invalid: None,
         };
 
         Self {
@@ -375,7 +473,11 @@ fn parse_int32() {
                 );
             }
             Err(err) => {
-                let expect = c.err.as_ref().expect(&format!("miss error: {}", c.input));
+                let err = unlocated(err);
+                let expect = c
+                    .err
+                    .as_ref()
+                    .unwrap_or_else(|| panic!("miss error: {}", c.input));
                 assert_eq!(&err, expect, "bad error for parse_int({}, 10, 32)", c.input);
             }
         }
@@ -389,19 +491,133 @@ fn parse_int64() {
             Ok(got) => {
                 assert!(c.err.is_none(), "unexpected error");
                 assert_eq!(
-                    got, c.output as i64,
+                    got, c.output,
                     "bad output for parse_int({}, 10, 64)",
                     c.input
                 );
             }
             Err(err) => {
-                let expect = c.err.as_ref().expect(&format!("miss error: {}", c.input));
+                let err = unlocated(err);
+                let expect = c
+                    .err
+                    .as_ref()
+                    .unwrap_or_else(|| panic!("miss error: {}", c.input));
                 assert_eq!(&err, expect, "bad error for parse_int({}, 10, 64)", c.input);
             }
         }
     }
 }
 
+#[test]
+fn parse_twos_complement() {
+    let ok = [
+        ("0xFFFFFFFF", 0, 32, -1),
+        ("0xffffffff", 0, 64, 0xffff_ffff),
+        ("0x80000000", 0, 32, i32::MIN as i64),
+        ("0x7fffffff", 0, 32, i32::MAX as i64),
+        ("0b1111_1110", 0, 8, -2),
+        ("0377", 0, 8, -1),
+        ("0o177", 0, 8, 127),
+        ("ff", 16, 8, -1),
+        ("1", 2, 1, -1),
+        ("0", 2, 1, 0),
+        ("18446744073709551615", 10, 64, -1),
+        ("8000000000000000", 16, 64, i64::MIN),
+        ("0", 10, 16, 0),
+    ];
+    for (input, base, bit_size, output) in ok {
+        assert_eq!(
+            strconv::parse_twos_complement(input, base, bit_size),
+            Ok(output),
+            "parse_twos_complement({}, {}, {})",
+            input,
+            base,
+            bit_size
+        );
+        assert_eq!(
+            strconv::parse_twos_complement_bytes(input.as_bytes(), base, bit_size),
+            Ok(output)
+        );
+    }
+
+    let err = |input: &'static str, err| NumError {
+        func: "parse_twos_complement",
+        num: input.into(),
+        err,
+        invalid: None,
+    };
+    let errs = [
+        (
+            "0x1_0000_0000",
+            0,
+            32,
+            NumErrorCause::OutOfRangeUnsigned {
+                bound_hint: u32::MAX as u64,
+            },
+        ),
+        (
+            "256",
+            10,
+            8,
+            NumErrorCause::OutOfRangeUnsigned { bound_hint: 255 },
+        ),
+        ("-1", 10, 8, NumErrorCause::InvalidSyntax),
+        ("0x", 0, 8, NumErrorCause::InvalidSyntax),
+        ("0x__1", 0, 8, NumErrorCause::InvalidSyntax),
+        ("1", 10, 65, NumErrorCause::InvalidBitSize(65)),
+        ("1", 37, 8, NumErrorCause::InvalidBase(37)),
+    ];
+    for (input, base, bit_size, cause) in errs {
+        let got = strconv::parse_twos_complement(input, base, bit_size).map_err(unlocated);
+        assert_eq!(got, Err(err(input, cause)), "{}", input);
+    }
+}
+
+#[test]
+fn atoi() {
+    let check = |input: &str, output: i64, expect: Option<&NumError>| match strconv::atoi(input) {
+        Ok(got) => {
+            assert!(expect.is_none(), "unexpected error");
+            assert_eq!(got as i64, output, "bad output for atoi({})", input);
+        }
+        Err(err) => {
+            assert_eq!(err.func, "atoi", "bad func for atoi({})", input);
+            let err = NumError {
+                func: "parse_int",
+                ..unlocated(err)
+            };
+            let expect = expect.unwrap_or_else(|| panic!("miss error: {}", input));
+            assert_eq!(&err, expect, "bad error for atoi({})", input);
+        }
+    };
+
+    if usize::BITS == 32 {
+        for c in PARSE_INT32_TESTS.iter() {
+            check(c.input, c.output as i64, c.err.as_ref());
+        }
+    } else {
+        for c in PARSE_INT64_TESTS.iter() {
+            check(c.input, c.output, c.err.as_ref());
+        }
+    }
+
+    // The fast path locates invalid characters as parse_int does.
+    for input in [
+        "+",
+        "-",
+        "1x",
+        "-12a45",
+        "+_1",
+        "1234567890123456x",
+        "12345678901234567x",
+    ] {
+        let got = strconv::atoi(input).unwrap_err();
+        let expect = strconv::parse_int(input, 10, 0).unwrap_err();
+        assert_eq!(got.invalid, expect.invalid, "atoi({})", input);
+        assert_eq!(got.err, expect.err);
+    }
+}
+
 #[test]
 fn parse_int64_base() {
     for c in PARSE_INT64_BASE_TESTS.iter() {
@@ -415,7 +631,8 @@ fn parse_int64_base() {
                 );
             }
             Err(err) => {
-                //let expect = c.err.as_ref().expect(&format!("miss error: {}", c.input));
+                let err = unlocated(err);
+                //let expect = c.err.as_ref().unwrap_or_else(|| panic!("miss error: {}", c.input));
                 assert_eq!(
                     Some(err),
                     c.err,
@@ -441,7 +658,11 @@ fn parse_uint32() {
                 );
             }
             Err(err) => {
-                let expect = c.err.as_ref().expect(&format!("miss error: {}", c.input));
+                let err = unlocated(err);
+                let expect = c
+                    .err
+                    .as_ref()
+                    .unwrap_or_else(|| panic!("miss error: {}", c.input));
                 assert_eq!(&err, expect, "bad error");
             }
         }
@@ -455,13 +676,17 @@ fn parse_uint64() {
             Ok(got) => {
                 assert!(c.err.is_none(), "unexpected error");
                 assert_eq!(
-                    got, c.output as u64,
+                    got, c.output,
                     "bad output for parse_uint({}, 10, 64)",
                     c.input
                 );
             }
             Err(err) => {
-                let expect = c.err.as_ref().expect(&format!("miss error: {}", c.input));
+                let err = unlocated(err);
+                let expect = c
+                    .err
+                    .as_ref()
+                    .unwrap_or_else(|| panic!("miss error: {}", c.input));
                 assert_eq!(&err, expect, "bad error");
             }
         }
@@ -475,15 +700,665 @@ fn parse_uint64_base() {
             Ok(got) => {
                 assert!(c.err.is_none(), "unexpected error");
                 assert_eq!(
-                    got, c.output as u64,
+                    got, c.output,
                     "bad output for parse_uint({}, {}, 64)",
                     c.input, c.base
                 );
             }
             Err(err) => {
-                let expect = c.err.as_ref().expect(&format!("miss error: {}", c.input));
+                let err = unlocated(err);
+                let expect = c
+                    .err
+                    .as_ref()
+                    .unwrap_or_else(|| panic!("miss error: {}", c.input));
                 assert_eq!(&err, expect, "bad error");
             }
         }
     }
 }
+
+#[test]
+fn parse_int_owned() {
+    for c in PARSE_INT64_BASE_TESTS.iter() {
+        let input = c.input.to_string();
+        match strconv::parse_int(&input, c.base, 64) {
+            Ok(got) => {
+                assert!(c.err.is_none(), "unexpected error");
+                assert_eq!(
+                    got, c.output,
+                    "bad output for parse_int({}, {}, 64)",
+                    input, c.base
+                );
+            }
+            Err(err) => {
+                let err = unlocated(err);
+                assert_eq!(
+                    Some(err),
+                    c.err,
+                    "bad error for parse_int({}, {}, 64)",
+                    input,
+                    c.base
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn parse_uint_owned() {
+    for c in PARSE_UINT64_BASE_TESTS.iter() {
+        let buf: Vec<u8> = c.input.bytes().collect();
+        let input = String::from_utf8(buf).unwrap();
+        match strconv::parse_uint(&input, c.base, 64) {
+            Ok(got) => {
+                assert!(c.err.is_none(), "unexpected error");
+                assert_eq!(
+                    got, c.output,
+                    "bad output for parse_uint({}, {}, 64)",
+                    input, c.base
+                );
+            }
+            Err(err) => {
+                let err = unlocated(err);
+                let expect = c
+                    .err
+                    .as_ref()
+                    .unwrap_or_else(|| panic!("miss error: {}", input));
+                assert_eq!(&err, expect, "bad error");
+            }
+        }
+    }
+}
+
+#[test]
+fn parse_int_formatted() {
+    for v in [0i64, 1, -1, 42, -12345, i32::MAX as i64, i64::MIN, i64::MAX] {
+        let input = format!("{}", v);
+        assert_eq!(strconv::parse_int(&input, 10, 64), Ok(v));
+
+        let input = format!("{:#x}", v.unsigned_abs());
+        let want = v.unsigned_abs();
+        assert_eq!(strconv::parse_uint(&input, 0, 64), Ok(want));
+    }
+}
+
+#[test]
+fn parse_bytes() {
+    for c in PARSE_INT64_BASE_TESTS.iter() {
+        let got = strconv::parse_int_bytes(c.input.as_bytes(), c.base, 64);
+        assert_eq!(
+            got,
+            strconv::parse_int(c.input, c.base, 64),
+            "bad result for parse_int_bytes({}, {}, 64)",
+            c.input,
+            c.base
+        );
+    }
+
+    for c in PARSE_UINT64_BASE_TESTS.iter() {
+        let got = strconv::parse_uint_bytes(c.input.as_bytes(), c.base, 64);
+        assert_eq!(
+            got,
+            strconv::parse_uint(c.input, c.base, 64),
+            "bad result for parse_uint_bytes({}, {}, 64)",
+            c.input,
+            c.base
+        );
+    }
+}
+
+#[test]
+fn parse_non_utf8_bytes() {
+    let test_vector: [(&[u8], &str); 4] = [
+        (b"\xff", "\u{fffd}"),
+        (b"12\xff", "12\u{fffd}"),
+        (b"0x\xc3\x28", "0x\u{fffd}("),
+        (b"\xe4\xb8\xad", "\u{4e2d}"),
+    ];
+
+    for (input, num) in test_vector {
+        let expect = NumError {
+            func: "parse_uint",
+            num: num.into(),
+            err: NumErrorCause::InvalidSyntax,
+            invalid: None,
+        };
+        let got = strconv::parse_uint_bytes(input, 0, 64).map_err(unlocated);
+        assert_eq!(got, Err(expect));
+
+        let signed: Vec<u8> = [b"-", input].concat();
+        let expect = NumError {
+            func: "parse_int",
+            num: format!("-{}", num).into(),
+            err: NumErrorCause::InvalidSyntax,
+            invalid: None,
+        };
+        let got = strconv::parse_int_bytes(&signed, 0, 64).map_err(unlocated);
+        assert_eq!(got, Err(expect));
+    }
+}
+
+#[test]
+fn parse_int128_base() {
+    for c in PARSE_INT128_BASE_TESTS.iter() {
+        match strconv::parse_int128(c.input, c.base, 128) {
+            Ok(got) => {
+                assert!(c.err.is_none(), "unexpected error");
+                assert_eq!(
+                    got, c.output,
+                    "bad output for parse_int128({}, {}, 128)",
+                    c.input, c.base
+                );
+            }
+            Err(err) => {
+                let err = unlocated(err);
+                assert_eq!(
+                    Some(err),
+                    c.err,
+                    "bad error for parse_int128({}, {}, 128)",
+                    c.input,
+                    c.base
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn parse_uint128_base() {
+    for c in PARSE_UINT128_BASE_TESTS.iter() {
+        match strconv::parse_uint128(c.input, c.base, 128) {
+            Ok(got) => {
+                assert!(c.err.is_none(), "unexpected error");
+                assert_eq!(
+                    got, c.output,
+                    "bad output for parse_uint128({}, {}, 128)",
+                    c.input, c.base
+                );
+            }
+            Err(err) => {
+                let err = unlocated(err);
+                let expect = c
+                    .err
+                    .as_ref()
+                    .unwrap_or_else(|| panic!("miss error: {}", c.input));
+                assert_eq!(&err, expect, "bad error");
+            }
+        }
+    }
+}
+
+#[test]
+fn parse_128_narrow_bit_size() {
+    // Results of narrower bit sizes agree with the 64-bit parsers.
+    for c in PARSE_INT64_BASE_TESTS.iter() {
+        for bit_size in [8, 16, 32, 64] {
+            let expect = strconv::parse_int(c.input, c.base, bit_size).map_err(|err| err.err);
+            let got = strconv::parse_int128(c.input, c.base, bit_size).map_err(|err| err.err);
+            match (got, expect) {
+                (Ok(got), Ok(expect)) => assert_eq!(got, expect as i128),
+                (
+                    Err(NumErrorCause::OutOfRangeSigned128 { bound_hint: got }),
+                    Err(NumErrorCause::OutOfRangeSigned { bound_hint: expect }),
+                ) => assert_eq!(got, expect as i128),
+                (got, expect) => assert_eq!(
+                    got.map_err(|err| err.to_string()),
+                    expect.map(|v| v as i128).map_err(|err| err.to_string()),
+                    "parse_int128({}, {}, {})",
+                    c.input,
+                    c.base,
+                    bit_size
+                ),
+            }
+        }
+    }
+
+    let err = strconv::parse_uint128("1", 10, 129).unwrap_err();
+    assert_eq!(err.err, NumErrorCause::InvalidBitSize(129));
+
+    let err = strconv::parse_uint("1", 10, 65).unwrap_err();
+    assert_eq!(err.err, NumErrorCause::InvalidBitSize(65));
+}
+
+struct ParsePrefixTest<T> {
+    input: &'static str,
+    base: u8,
+    bit_size: u8,
+    output: Result<(T, usize), NumError<'static>>,
+}
+
+impl<T> ParsePrefixTest<T> {
+    fn ok(input: &'static str, base: u8, bit_size: u8, v: T, n: usize) -> Self {
+        Self {
+            input,
+            base,
+            bit_size,
+            output: Ok((v, n)),
+        }
+    }
+
+    fn err(input: &'static str, base: u8, bit_size: u8, err: NumErrorCause) -> Self {
+        let err = NumError {
+            func: "parse_uint_prefix",
+            num: input.into(),
+            err,
+            invalid: None,
+        };
+
+        Self {
+            input,
+            base,
+            bit_size,
+            output: Err(err),
+        }
+    }
+
+    fn err_signed(input: &'static str, base: u8, bit_size: u8, err: NumErrorCause) -> Self {
+        let err = NumError {
+            func: "parse_int_prefix",
+            num: input.into(),
+            err,
+            invalid: None,
+        };
+
+        Self {
+            input,
+            base,
+            bit_size,
+            output: Err(err),
+        }
+    }
+}
+
+#[test]
+fn parse_uint_prefix() {
+    let test_vector = vec![
+        ParsePrefixTest::ok("123abc", 10, 64, 123u64, 3),
+        ParsePrefixTest::ok("123", 10, 64, 123, 3),
+        ParsePrefixTest::ok("123abc", 16, 64, 0x123abc, 6),
+        ParsePrefixTest::ok("0x1F)", 0, 64, 0x1f, 4),
+        ParsePrefixTest::ok("0X1f)", 0, 64, 0x1f, 4),
+        ParsePrefixTest::ok("0x)", 0, 64, 0, 1),
+        ParsePrefixTest::ok("0xg", 0, 64, 0, 1),
+        ParsePrefixTest::ok("0b102", 0, 64, 2, 4),
+        ParsePrefixTest::ok("0b2", 0, 64, 0, 1),
+        ParsePrefixTest::ok("0o17 ", 0, 64, 0o17, 4),
+        ParsePrefixTest::ok("0778", 0, 64, 0o77, 3),
+        ParsePrefixTest::ok("08", 0, 64, 0, 1),
+        ParsePrefixTest::ok("0", 0, 64, 0, 1),
+        ParsePrefixTest::ok("1_000 rest", 0, 64, 1000, 5),
+        ParsePrefixTest::ok("0x_ff,", 0, 64, 0xff, 5),
+        ParsePrefixTest::ok("1_2", 10, 64, 1, 1),
+        ParsePrefixTest::ok("255+1", 10, 8, 255, 3),
+        ParsePrefixTest::ok("18446744073709551615.", 10, 64, u64::MAX, 20),
+        ParsePrefixTest::err("", 10, 64, NumErrorCause::InvalidSyntax),
+        ParsePrefixTest::err("abc", 10, 64, NumErrorCause::InvalidSyntax),
+        ParsePrefixTest::err("-1", 10, 64, NumErrorCause::InvalidSyntax),
+        ParsePrefixTest::err("_1", 0, 64, NumErrorCause::InvalidSyntax),
+        ParsePrefixTest::err("1__0", 0, 64, NumErrorCause::InvalidSyntax),
+        ParsePrefixTest::err("1_000_ x", 0, 64, NumErrorCause::InvalidSyntax),
+        ParsePrefixTest::err("0x_)", 0, 64, NumErrorCause::InvalidSyntax),
+        ParsePrefixTest::err("1", 1, 64, NumErrorCause::InvalidBase(1)),
+        ParsePrefixTest::err("1", 10, 65, NumErrorCause::InvalidBitSize(65)),
+        ParsePrefixTest::err(
+            "256+1",
+            10,
+            8,
+            NumErrorCause::OutOfRangeUnsigned { bound_hint: 255 },
+        ),
+        ParsePrefixTest::err(
+            "18446744073709551616.",
+            10,
+            64,
+            NumErrorCause::OutOfRangeUnsigned {
+                bound_hint: u64::MAX,
+            },
+        ),
+    ];
+
+    for c in test_vector {
+        let got = strconv::parse_uint_prefix(c.input, c.base, c.bit_size).map_err(unlocated);
+        assert_eq!(
+            got, c.output,
+            "parse_uint_prefix({}, {}, {})",
+            c.input, c.base, c.bit_size
+        );
+    }
+}
+
+#[test]
+fn parse_int_prefix() {
+    let test_vector = vec![
+        ParsePrefixTest::ok("-123abc", 10, 64, -123i64, 4),
+        ParsePrefixTest::ok("+123abc", 10, 64, 123, 4),
+        ParsePrefixTest::ok("-12)", 10, 64, -12, 3),
+        ParsePrefixTest::ok("+0x_ff,", 0, 64, 0xff, 6),
+        ParsePrefixTest::ok("-0x)", 0, 64, 0, 2),
+        ParsePrefixTest::ok("-128,", 10, 8, -128, 4),
+        ParsePrefixTest::ok("-9223372036854775808 ", 10, 64, i64::MIN, 20),
+        ParsePrefixTest::err_signed("", 10, 64, NumErrorCause::InvalidSyntax),
+        ParsePrefixTest::err_signed("-", 10, 64, NumErrorCause::InvalidSyntax),
+        ParsePrefixTest::err_signed("-x", 10, 64, NumErrorCause::InvalidSyntax),
+        ParsePrefixTest::err_signed("--1", 10, 64, NumErrorCause::InvalidSyntax),
+        ParsePrefixTest::err_signed("-_1", 0, 64, NumErrorCause::InvalidSyntax),
+        ParsePrefixTest::err_signed(
+            "-129,",
+            10,
+            8,
+            NumErrorCause::OutOfRangeSigned { bound_hint: -128 },
+        ),
+        ParsePrefixTest::err_signed(
+            "128;",
+            10,
+            8,
+            NumErrorCause::OutOfRangeSigned { bound_hint: 127 },
+        ),
+    ];
+
+    for c in test_vector {
+        let got = strconv::parse_int_prefix(c.input, c.base, c.bit_size).map_err(unlocated);
+        assert_eq!(
+            got, c.output,
+            "parse_int_prefix({}, {}, {})",
+            c.input, c.base, c.bit_size
+        );
+    }
+}
+
+#[test]
+fn parse_prefix_whole_input() {
+    // A whole valid input is consumed entirely, with the same value.
+    for c in PARSE_INT64_BASE_TESTS.iter().filter(|c| c.err.is_none()) {
+        let got = strconv::parse_int_prefix(c.input, c.base, 64);
+        assert_eq!(got, Ok((c.output, c.input.len())), "{}", c.input);
+    }
+
+    for c in PARSE_UINT64_BASE_TESTS.iter().filter(|c| c.err.is_none()) {
+        let got = strconv::parse_uint_prefix(c.input, c.base, 64);
+        assert_eq!(got, Ok((c.output, c.input.len())), "{}", c.input);
+    }
+}
+
+#[test]
+fn invalid_char() {
+    // (input, base, offset, ch)
+    let test_vector = [
+        ("12345x", 10, Some((5, 'x'))),
+        ("-12345x", 10, Some((6, 'x'))),
+        ("1_2_3_4_5", 10, Some((1, '_'))),
+        ("-_12345", 0, Some((1, '_'))),
+        ("_12345", 0, Some((0, '_'))),
+        ("1__2345", 0, Some((2, '_'))),
+        ("12345_", 0, Some((5, '_'))),
+        ("-_0x12345", 0, Some((1, '_'))),
+        ("0x__12345", 0, Some((3, '_'))),
+        ("0x1234__5", 0, Some((7, '_'))),
+        ("0x12345_", 0, Some((7, '_'))),
+        ("0x__12g", 0, Some((3, '_'))),
+        ("1_x", 0, Some((1, '_'))),
+        ("0x+f", 0, Some((2, '+'))),
+        ("+0x-f", 0, Some((3, '-'))),
+        ("0xabcdefg123", 0, Some((8, 'g'))),
+        ("01777777777777777777778", 0, Some((22, '8'))),
+        ("0b102", 0, Some((4, '2'))),
+        ("0x", 0, Some((1, 'x'))),
+        ("12\u{4e2d}", 10, Some((2, '\u{4e2d}'))),
+        ("", 10, None),
+        ("-", 10, None),
+        ("0x_", 0, Some((2, '_'))),
+    ];
+
+    for (input, base, expect) in test_vector {
+        let expect = expect.map(|(offset, ch)| InvalidChar { offset, ch });
+
+        let err = strconv::parse_int(input, base, 64).unwrap_err();
+        assert_eq!(err.err, NumErrorCause::InvalidSyntax, "{}", input);
+        assert_eq!(err.invalid, expect, "parse_int({}, {}, 64)", input, base);
+
+        if !input.starts_with(['-', '+']) {
+            let err = strconv::parse_uint(input, base, 64).unwrap_err();
+            assert_eq!(err.invalid, expect, "parse_uint({}, {}, 64)", input, base);
+        }
+    }
+
+    let err = strconv::parse_uint_bytes(b"12\xff", 10, 64).unwrap_err();
+    let expect = InvalidChar {
+        offset: 2,
+        ch: char::REPLACEMENT_CHARACTER,
+    };
+    assert_eq!(err.invalid, Some(expect));
+
+    let err = strconv::parse_int_prefix("-12_)", 0, 64).unwrap_err();
+    let expect = InvalidChar { offset: 3, ch: '_' };
+    assert_eq!(err.invalid, Some(expect));
+
+    let err = strconv::parse_uint_prefix("xyz", 10, 64).unwrap_err();
+    let expect = InvalidChar { offset: 0, ch: 'x' };
+    assert_eq!(err.invalid, Some(expect));
+}
+
+#[test]
+fn invalid_char_display() {
+    let err = strconv::parse_int("0x1__2", 0, 64).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "strconv.parse_int: parsing '0x1__2': invalid syntax"
+    );
+    assert_eq!(
+        format!("{:#}", err),
+        "strconv.parse_int: parsing '0x1__2': invalid syntax at byte 4 ('_')"
+    );
+
+    let err = strconv::parse_int("", 0, 64).unwrap_err();
+    assert_eq!(
+        format!("{:#}", err),
+        "strconv.parse_int: parsing '': invalid syntax"
+    );
+
+    let err = strconv::parse_uint("256", 0, 8).unwrap_err();
+    assert_eq!(
+        format!("{:#}", err),
+        "strconv.parse_uint: parsing '256': unsigned value out of range: 255"
+    );
+}
+
+#[test]
+fn uppercase_hex_underscores() {
+    assert_eq!(strconv::parse_uint("0x_A_B", 0, 64), Ok(0xab));
+    assert_eq!(strconv::parse_int("-0XFF_FF", 0, 64), Ok(-0xffff));
+    assert_eq!(
+        strconv::parse_uint("0xFFFF_FFFF", 0, 32),
+        Ok(u32::MAX as u64)
+    );
+}
+
+#[test]
+fn borrowed_error() {
+    let input = String::from("12345x");
+    let err = strconv::parse_int(&input, 10, 64).unwrap_err();
+    assert!(matches!(err.num, Cow::Borrowed("12345x")));
+    assert_eq!(err.func, "parse_int");
+
+    let err = strconv::parse_uint_bytes(b"12\xff", 10, 64).unwrap_err();
+    assert!(matches!(err.num, Cow::Owned(_)));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn into_owned() {
+    let owned = {
+        let input = String::from("-0x_8000_0001");
+        let err = strconv::parse_int(&input, 0, 32).unwrap_err();
+        err.into_owned()
+    };
+
+    let expect = NumError {
+        func: "parse_int",
+        num: "-0x_8000_0001".into(),
+        err: NumErrorCause::OutOfRangeSigned {
+            bound_hint: i32::MIN as i64,
+        },
+        invalid: None,
+    };
+    assert_eq!(owned, expect);
+
+    let boxed: Box<dyn std::error::Error> = Box::new(owned);
+    assert_eq!(
+        boxed.to_string(),
+        "strconv.parse_int: parsing '-0x_8000_0001': signed value out of range: -2147483648"
+    );
+}
+
+#[test]
+fn parse_const() {
+    const MASK: i64 = strconv::must_parse_int("-0x_FF_FF", 0, 32);
+    assert_eq!(MASK, -0xffff);
+
+    const REG: u64 = strconv::must_parse_uint("0b_1010_0101", 0, 8);
+    assert_eq!(REG, 0b1010_0101);
+
+    // The const parsers agree with the runtime ones, error causes included.
+    for c in PARSE_INT64_BASE_TESTS.iter() {
+        for bit_size in [0, 8, 32, 64, 65] {
+            let expect = strconv::parse_int(c.input, c.base, bit_size).map_err(|err| err.err);
+            let got = strconv::parse_int_const(c.input, c.base, bit_size);
+            assert_eq!(
+                got, expect,
+                "parse_int_const({}, {}, {})",
+                c.input, c.base, bit_size
+            );
+        }
+    }
+
+    for c in PARSE_UINT64_BASE_TESTS.iter() {
+        for bit_size in [0, 8, 32, 64, 65] {
+            let expect = strconv::parse_uint(c.input, c.base, bit_size).map_err(|err| err.err);
+            let got = strconv::parse_uint_const(c.input, c.base, bit_size);
+            assert_eq!(
+                got, expect,
+                "parse_uint_const({}, {}, {})",
+                c.input, c.base, bit_size
+            );
+        }
+    }
+
+    for c in PARSE_INT32_TESTS.iter() {
+        let expect = strconv::parse_int(c.input, 10, 32).map_err(|err| err.err);
+        assert_eq!(strconv::parse_int_const(c.input, 10, 32), expect);
+    }
+
+    assert_eq!(
+        strconv::parse_uint_const("1", 37, 64),
+        Err(NumErrorCause::InvalidBase(37))
+    );
+}
+
+#[test]
+#[should_panic(expected = "strconv.parse_uint: invalid syntax")]
+fn must_parse_uint_panic() {
+    let s = String::from("0x__1");
+    strconv::must_parse_uint(&s, 0, 64);
+}
+
+#[test]
+fn parse_long_digit_runs() {
+    assert_eq!(
+        strconv::parse_uint("18446744073709551615", 10, 64),
+        Ok(u64::MAX)
+    );
+    assert_eq!(
+        strconv::parse_uint("0000000000000000000018446744073709551615", 10, 64),
+        Ok(u64::MAX)
+    );
+    assert_eq!(
+        strconv::parse_uint("0123456789abcdef", 16, 64),
+        Ok(0x0123456789abcdef)
+    );
+    assert_eq!(
+        strconv::parse_uint("0xFEDCBA9876543210", 0, 64),
+        Ok(0xfedcba9876543210)
+    );
+    assert_eq!(
+        strconv::parse_uint128("ffffffffffffffffffffffffffffffff", 16, 128),
+        Ok(u128::MAX)
+    );
+
+    // Every position of an invalid byte within the first 16 digits.
+    for offset in 0..16 {
+        let mut input = *b"1234567890123456";
+        input[offset] = b'g';
+        let err = strconv::parse_uint_bytes(&input, 16, 64).unwrap_err();
+        assert_eq!(err.invalid.map(|v| v.offset), Some(offset));
+
+        let got = strconv::parse_uint_prefix(std::str::from_utf8(&input).unwrap(), 10, 64);
+        if offset == 0 {
+            assert!(got.is_err());
+        } else {
+            assert_eq!(got.map(|(_, n)| n), Ok(offset));
+        }
+    }
+}
+
+#[test]
+fn parse_long_digit_runs_random() {
+    // The 8-digit shortcut of the runtime parsers must agree with the
+    // byte-wise const parser, and locate invalid bytes the same way.
+    const ALPHABET: &[u8] = b"01234567890123456789abcdefABCDEF_gx";
+
+    let mut seed = 0x2545_f491_4f6c_dd1du64;
+    let mut rand = move || {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        seed
+    };
+
+    for _ in 0..20_000 {
+        let len = (rand() % 42) as usize;
+        let mut input: String = (0..len)
+            .map(|_| ALPHABET[(rand() % 20) as usize] as char)
+            .collect();
+        if rand() % 4 == 0 && !input.is_empty() {
+            let i = (rand() as usize) % input.len();
+            let c = ALPHABET[(rand() as usize) % ALPHABET.len()] as char;
+            input.replace_range(i..i + 1, c.encode_utf8(&mut [0; 4]));
+        }
+
+        for base in [0, 10, 16] {
+            for bit_size in [0, 40, 64] {
+                let got = strconv::parse_uint(&input, base, bit_size);
+                let expect = strconv::parse_uint_const(&input, base, bit_size);
+                assert_eq!(
+                    got.as_ref().map_err(|err| &err.err),
+                    expect.as_ref(),
+                    "parse_uint({}, {}, {})",
+                    input,
+                    base,
+                    bit_size
+                );
+
+                if base == 0 {
+                    continue;
+                }
+
+                let first_invalid = input
+                    .bytes()
+                    .position(|c| !(c as char).is_digit(base as u32));
+                if let Err(err) = got {
+                    if let Some(v) = err.invalid {
+                        assert_eq!(Some(v.offset), first_invalid, "{}", input);
+                    }
+                }
+
+                if let Ok((_, n)) = strconv::parse_uint_prefix(&input, base, bit_size) {
+                    assert_eq!(n, first_invalid.unwrap_or(input.len()), "{}", input);
+                }
+            }
+
+            if base != 0 && !input.is_empty() {
+                let got = strconv::parse_uint128(&input, base, 128).ok();
+                assert_eq!(got, u128::from_str_radix(&input, base as u32).ok());
+            }
+        }
+    }
+}
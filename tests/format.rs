@@ -0,0 +1,86 @@
+use std::fmt::Debug;
+
+use strconv::{FormatBuffer, FormatRadix, ParseRadix};
+
+/// Renders v in base the plain way, as a reference.
+fn naive(v: i128, base: u8) -> String {
+    const DIGITS: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+    let mut u = v.unsigned_abs();
+    let mut out = Vec::new();
+    loop {
+        out.push(DIGITS[(u % base as u128) as usize]);
+        u /= base as u128;
+        if u == 0 {
+            break;
+        }
+    }
+    if v < 0 {
+        out.push(b'-');
+    }
+    out.reverse();
+    String::from_utf8(out).unwrap()
+}
+
+fn run<T>(values: &[T])
+where
+    T: FormatRadix + ParseRadix + PartialEq + Debug + TryInto<i128>,
+{
+    let mut buf = FormatBuffer::new();
+    for &v in values {
+        for base in 2..=36 {
+            let got = buf.format(v, base);
+            if let Ok(w) = v.try_into() {
+                assert_eq!(got, naive(w, base), "format({:?}, {})", v, base);
+            }
+            assert_eq!(strconv::parse::<T>(got, base), Ok(v), "{}", got);
+        }
+    }
+}
+
+#[test]
+fn format_all_widths() {
+    run(&[0i8, 1, -1, 10, i8::MAX, i8::MIN]);
+    run(&[0i16, -1000, i16::MAX, i16::MIN]);
+    run(&[0i32, 123456789, i32::MAX, i32::MIN]);
+    run(&[0i64, -0x123456789abcdef, i64::MAX, i64::MIN]);
+    run(&[0isize, isize::MAX, isize::MIN]);
+    run(&[0i128, i128::MAX, i128::MIN, i64::MIN as i128 - 1]);
+
+    run(&[0u8, 9, 10, 99, 100, u8::MAX]);
+    run(&[0u16, u16::MAX]);
+    run(&[0u32, u32::MAX]);
+    run(&[0u64, 1 << 63, u64::MAX]);
+    run(&[0usize, usize::MAX]);
+    run(&[0u128, u64::MAX as u128 + 1, i128::MAX as u128]);
+}
+
+#[test]
+fn format_longest() {
+    let mut buf = FormatBuffer::default();
+
+    let s = buf.format(i128::MIN, 2);
+    assert_eq!(s.len(), 129);
+    assert_eq!(s, format!("-1{}", "0".repeat(127)));
+
+    assert_eq!(buf.format(u128::MAX, 2), "1".repeat(128));
+    assert_eq!(
+        buf.format(u128::MAX, 10),
+        "340282366920938463463374607431768211455"
+    );
+    assert_eq!(buf.format(i64::MIN, 16), "-8000000000000000");
+}
+
+#[test]
+fn format_reuse() {
+    let mut buf = FormatBuffer::new();
+    assert_eq!(buf.format(123456789u32, 10), "123456789");
+    assert_eq!(buf.format(7u8, 10), "7");
+    assert_eq!(buf.format(-35i16, 36), "-z");
+}
+
+#[test]
+#[should_panic(expected = "strconv.FormatBuffer::format: invalid base 37")]
+fn format_invalid_base() {
+    FormatBuffer::new().format(1u8, 37);
+}
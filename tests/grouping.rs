@@ -0,0 +1,219 @@
+use strconv::{Grouping, Locale, NumErrorCause};
+
+struct GroupedTest {
+    input: &'static str,
+    grouping: Grouping,
+    // the value, or the offset of the invalid character
+    output: Result<i64, Option<usize>>,
+}
+
+const fn ok(input: &'static str, grouping: Grouping, v: i64) -> GroupedTest {
+    GroupedTest {
+        input,
+        grouping,
+        output: Ok(v),
+    }
+}
+
+const fn err(input: &'static str, grouping: Grouping, offset: Option<usize>) -> GroupedTest {
+    GroupedTest {
+        input,
+        grouping,
+        output: Err(offset),
+    }
+}
+
+const PARSE_GROUPED_TESTS: &[GroupedTest] = &[
+    ok("0", Grouping::COMMA, 0),
+    ok("-0", Grouping::COMMA, 0),
+    ok("1", Grouping::COMMA, 1),
+    ok("1234567", Grouping::COMMA, 1234567),
+    ok("1,234,567", Grouping::COMMA, 1234567),
+    ok("+1,234,567", Grouping::COMMA, 1234567),
+    ok("-1,234,567", Grouping::COMMA, -1234567),
+    ok("12,345", Grouping::COMMA, 12345),
+    ok("123,456", Grouping::COMMA, 123456),
+    ok("001,000", Grouping::COMMA, 1000),
+    ok("1.234.567", Grouping::PERIOD, 1234567),
+    ok("1\u{a0}234\u{a0}567", Grouping::NBSP, 1234567),
+    ok("1\u{202f}234", Grouping::NARROW_NBSP, 1234),
+    ok("1\u{2019}234", Grouping::APOSTROPHE, 1234),
+    ok("12,34,567", Grouping::INDIAN, 1234567),
+    ok("1,00,00,000", Grouping::INDIAN, 10000000),
+    ok("1,234", Grouping::INDIAN, 1234),
+    ok("123", Grouping::INDIAN, 123),
+    ok("9,223,372,036,854,775,807", Grouping::COMMA, i64::MAX),
+    ok("-9,223,372,036,854,775,808", Grouping::COMMA, i64::MIN),
+    err("", Grouping::COMMA, None),
+    err("-", Grouping::COMMA, None),
+    err(",123", Grouping::COMMA, Some(0)),
+    err("-,123", Grouping::COMMA, Some(1)),
+    err("123,", Grouping::COMMA, Some(3)),
+    err("1,,234", Grouping::COMMA, Some(2)),
+    err("1234,567", Grouping::COMMA, Some(4)),
+    err("1,23,456", Grouping::COMMA, Some(4)),
+    err("1,2345", Grouping::COMMA, Some(1)),
+    err("1,234,56", Grouping::COMMA, Some(5)),
+    err("1.234", Grouping::COMMA, Some(1)),
+    err("1,234", Grouping::PERIOD, Some(1)),
+    err("1 234", Grouping::NBSP, Some(1)),
+    err("1\u{a0}234", Grouping::NARROW_NBSP, Some(1)),
+    err("1,234,567", Grouping::INDIAN, Some(5)),
+    err("123,456", Grouping::INDIAN, Some(3)),
+    err("12,34,56", Grouping::INDIAN, Some(5)),
+    err("12x", Grouping::COMMA, Some(2)),
+    err("1,2_34", Grouping::COMMA, Some(3)),
+];
+
+#[test]
+fn parse_int_grouped() {
+    for c in PARSE_GROUPED_TESTS {
+        let got = strconv::parse_int_grouped(c.input, c.grouping, 64);
+        match c.output {
+            Ok(v) => assert_eq!(got, Ok(v), "parse_int_grouped({:?})", c.input),
+            Err(offset) => {
+                let err = got.unwrap_err();
+                assert_eq!(err.func, "parse_int_grouped");
+                assert_eq!(err.err, NumErrorCause::InvalidSyntax, "{:?}", c.input);
+                assert_eq!(
+                    err.invalid.map(|v| v.offset),
+                    offset,
+                    "parse_int_grouped({:?})",
+                    c.input
+                );
+            }
+        }
+
+        let got = strconv::parse_uint_grouped(c.input, c.grouping, 64);
+        match (c.output, c.input.bytes().next()) {
+            (Ok(v), Some(b'0'..=b'9')) => assert_eq!(got, Ok(v as u64)),
+            _ => assert_eq!(got.unwrap_err().err, NumErrorCause::InvalidSyntax),
+        }
+    }
+}
+
+#[test]
+fn parse_grouped_range() {
+    let err = strconv::parse_int_grouped("2,147,483,648", Grouping::COMMA, 32).unwrap_err();
+    assert_eq!(err.err.bound_hint::<i32>(), Some(i32::MAX));
+
+    let err = strconv::parse_int_grouped("-12,34,56,789", Grouping::INDIAN, 16).unwrap_err();
+    assert_eq!(err.err.bound_hint::<i16>(), Some(i16::MIN));
+
+    let err = strconv::parse_int_grouped("99.999.999.999.999.999.999", Grouping::PERIOD, 64);
+    assert_eq!(err.unwrap_err().err.bound_hint::<i64>(), Some(i64::MAX));
+
+    let err = strconv::parse_uint_grouped("18,446,744,073,709,551,616", Grouping::COMMA, 0);
+    assert_eq!(err.unwrap_err().err.bound_hint::<u64>(), Some(u64::MAX));
+    assert_eq!(
+        strconv::parse_uint_grouped("18,446,744,073,709,551,615", Grouping::COMMA, 0),
+        Ok(u64::MAX)
+    );
+
+    let err = strconv::parse_uint_grouped("1", Grouping::COMMA, 65).unwrap_err();
+    assert_eq!(err.err, NumErrorCause::InvalidBitSize(65));
+}
+
+#[test]
+fn locale_lookup() {
+    let tests = [
+        ("en", Grouping::COMMA, '.'),
+        ("en-US", Grouping::COMMA, '.'),
+        ("EN_in", Grouping::INDIAN, '.'),
+        ("de", Grouping::PERIOD, ','),
+        ("de-DE", Grouping::PERIOD, ','),
+        ("de-CH", Grouping::APOSTROPHE, '.'),
+        ("fr-FR", Grouping::NARROW_NBSP, ','),
+        ("ru", Grouping::NBSP, ','),
+        ("hi-IN", Grouping::INDIAN, '.'),
+        ("pt-BR", Grouping::PERIOD, ','),
+        ("zh-Hant-TW", Grouping::COMMA, '.'),
+    ];
+    for (tag, grouping, decimal) in tests {
+        let got = Locale::lookup(tag);
+        assert_eq!(got, Some(Locale { grouping, decimal }), "lookup({})", tag);
+    }
+
+    for tag in ["", "-", "xx", "x-de", "deu"] {
+        assert_eq!(Locale::lookup(tag), None, "lookup({})", tag);
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn format_grouped() {
+    let tests = [
+        (0, Grouping::COMMA, "0"),
+        (-1, Grouping::COMMA, "-1"),
+        (123, Grouping::COMMA, "123"),
+        (1234, Grouping::COMMA, "1,234"),
+        (-123456, Grouping::COMMA, "-123,456"),
+        (1234567, Grouping::PERIOD, "1.234.567"),
+        (1234567, Grouping::NBSP, "1\u{a0}234\u{a0}567"),
+        (1234567, Grouping::INDIAN, "12,34,567"),
+        (10000000, Grouping::INDIAN, "1,00,00,000"),
+        (i64::MIN, Grouping::COMMA, "-9,223,372,036,854,775,808"),
+        (1234567, Grouping::new('_', 4, 4), "123_4567"),
+    ];
+    for (input, grouping, output) in tests {
+        assert_eq!(strconv::format_int_grouped(input, grouping), output);
+    }
+
+    assert_eq!(
+        strconv::format_uint_grouped(u64::MAX, Grouping::APOSTROPHE),
+        "18’446’744’073’709’551’615"
+    );
+
+    // Every format is parsed back.
+    let groupings = [
+        Grouping::COMMA,
+        Grouping::NARROW_NBSP,
+        Grouping::INDIAN,
+        Grouping::new('\'', 2, 4),
+    ];
+    let mut v = 1i64;
+    while let Some(next) = v.checked_mul(7) {
+        for grouping in groupings {
+            for x in [v, -v, v - 1, 1 - v] {
+                let s = strconv::format_int_grouped(x, grouping);
+                assert_eq!(strconv::parse_int_grouped(&s, grouping, 64), Ok(x), "{}", s);
+            }
+        }
+        v = next;
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn grouped_formatted_len() {
+    let groupings = [
+        Grouping::COMMA,
+        Grouping::NBSP,
+        Grouping::NARROW_NBSP,
+        Grouping::INDIAN,
+        Grouping::new('_', 1, 1),
+        Grouping::new('_', 4, 7),
+    ];
+    let mut values = vec![0, i64::MIN, i64::MIN + 1, i64::MAX];
+    let mut p = 1i64;
+    while let Some(next) = p.checked_mul(10) {
+        values.extend([p - 1, p, -p, 1 - p]);
+        p = next;
+    }
+
+    for grouping in groupings {
+        for &i in &values {
+            let want = strconv::format_int_grouped(i, grouping).len();
+            assert_eq!(grouping.formatted_len(i), want, "formatted_len({})", i);
+
+            let u = i as u64;
+            let want = strconv::format_uint_grouped(u, grouping).len();
+            assert_eq!(
+                grouping.formatted_uint_len(u),
+                want,
+                "formatted_uint_len({})",
+                u
+            );
+        }
+    }
+}
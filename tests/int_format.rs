@@ -0,0 +1,158 @@
+use strconv::IntFormat;
+
+struct IntFormatTest {
+    format: IntFormat,
+    input: i64,
+    output: &'static str,
+}
+
+const fn t(format: IntFormat, input: i64, output: &'static str) -> IntFormatTest {
+    IntFormatTest {
+        format,
+        input,
+        output,
+    }
+}
+
+const HEX: IntFormat = IntFormat::new(16);
+
+const INT_FORMAT_TESTS: &[IntFormatTest] = &[
+    t(IntFormat::new(10), 0, "0"),
+    t(IntFormat::new(10), -42, "-42"),
+    t(IntFormat::new(10).plus(true), 42, "+42"),
+    t(IntFormat::new(10).plus(true), 0, "+0"),
+    t(IntFormat::new(10).plus(true), -42, "-42"),
+    t(IntFormat::new(10).width(6), -42, "   -42"),
+    t(IntFormat::new(10).width(6).fill('0'), -42, "-00042"),
+    t(IntFormat::new(10).width(6).fill('*'), 42, "****42"),
+    t(
+        IntFormat::new(10).width(6).fill('\u{a0}'),
+        42,
+        "\u{a0}\u{a0}\u{a0}\u{a0}42",
+    ),
+    t(IntFormat::new(10).width(2), 12345, "12345"),
+    t(IntFormat::new(10).prefix(true), 42, "42"),
+    t(HEX, 0xbeef, "beef"),
+    t(HEX.uppercase(true), 0xbeef, "BEEF"),
+    t(HEX.uppercase(true).prefix(true), -0xbeef, "-0xBEEF"),
+    t(
+        HEX.width(9).fill('0').plus(true).uppercase(true),
+        0x2a,
+        "+0000002A",
+    ),
+    t(HEX.width(10).fill('0').prefix(true), 0x2a, "0x0000002a"),
+    t(HEX.width(10).prefix(true), 0x2a, "      0x2a"),
+    t(HEX.width(4).fill('0').prefix(true), 0xbeef, "0xbeef"),
+    t(
+        IntFormat::new(2).prefix(true).width(8).fill('0'),
+        5,
+        "0b000101",
+    ),
+    t(IntFormat::new(8).prefix(true), -8, "-0o10"),
+    t(IntFormat::new(36).uppercase(true), 35, "Z"),
+    t(
+        HEX.uppercase(true).prefix(true),
+        i64::MIN,
+        "-0x8000000000000000",
+    ),
+];
+
+#[test]
+fn int_format() {
+    for c in INT_FORMAT_TESTS {
+        let mut got = String::new();
+        c.format.write_int(&mut got, c.input).unwrap();
+        assert_eq!(got, c.output, "{:?}.write_int({})", c.format, c.input);
+
+        #[cfg(feature = "alloc")]
+        {
+            assert_eq!(c.format.format_int(c.input), c.output);
+
+            let mut got = b"abc".to_vec();
+            c.format.append_int(&mut got, c.input);
+            assert_eq!(got, format!("abc{}", c.output).as_bytes());
+
+            if c.input >= 0 {
+                let u = c.input as u64;
+                assert_eq!(c.format.format_uint(u), c.output);
+
+                let mut got = Vec::new();
+                c.format.append_uint(&mut got, u);
+                assert_eq!(got, c.output.as_bytes());
+            }
+        }
+    }
+}
+
+#[test]
+fn int_format_matches_std() {
+    let values = [0u64, 1, 9, 10, 0x2a, 0xbeef, 1 << 40, u64::MAX];
+
+    for width in 0..24 {
+        for &v in &values {
+            let mut got = String::new();
+            let f = IntFormat::new(16).width(width).fill('0');
+            f.prefix(true)
+                .uppercase(true)
+                .write_uint(&mut got, v)
+                .unwrap();
+            assert_eq!(got, format!("{:#0width$X}", v, width = width));
+
+            let mut got = String::new();
+            f.write_uint(&mut got, v).unwrap();
+            assert_eq!(got, format!("{:0width$x}", v, width = width));
+
+            let mut got = String::new();
+            let f = IntFormat::new(2).width(width).prefix(true);
+            f.write_uint(&mut got, v).unwrap();
+            assert_eq!(got, format!("{:>#width$b}", v, width = width));
+
+            let i = v as i64;
+            let mut got = String::new();
+            let f = IntFormat::new(10).width(width).plus(true);
+            f.fill('0').write_int(&mut got, i).unwrap();
+            assert_eq!(got, format!("{:+0width$}", i, width = width));
+
+            let mut got = String::new();
+            f.fill('_').write_int(&mut got, i).unwrap();
+            assert_eq!(got, format!("{:_>+width$}", i, width = width));
+        }
+    }
+}
+
+#[test]
+fn int_format_formatted_len() {
+    let mut values = vec![0, i64::MIN, i64::MIN + 1, i64::MAX];
+    for base in [2, 8, 10, 16, 36] {
+        let mut p = 1i64;
+        while let Some(next) = p.checked_mul(base) {
+            values.extend([p - 1, p, -p]);
+            p = next;
+        }
+    }
+
+    for base in [2, 3, 8, 10, 16, 36] {
+        for width in [0, 1, 5, 20, 70] {
+            for fill in [' ', '0', '·', '\u{1f600}'] {
+                let f = IntFormat::new(base).width(width).fill(fill);
+                for f in [f, f.plus(true), f.prefix(true)] {
+                    for &i in &values {
+                        let mut got = String::new();
+                        f.write_int(&mut got, i).unwrap();
+                        assert_eq!(f.formatted_len(i), got.len(), "{:?} {}", f, i);
+
+                        let mut got = String::new();
+                        f.write_uint(&mut got, i as u64).unwrap();
+                        assert_eq!(f.formatted_uint_len(i as u64), got.len(), "{:?} {}", f, i);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "strconv.IntFormat::format: invalid base 37")]
+fn int_format_invalid_base() {
+    IntFormat::new(37).write_int(&mut String::new(), 1).unwrap();
+}
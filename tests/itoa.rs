@@ -0,0 +1,375 @@
+// The formatters return String or append to Vec<u8>, which requires alloc.
+#![cfg(feature = "alloc")]
+
+struct FormatTest<T> {
+    input: T,
+    base: u8,
+    output: &'static str,
+}
+
+const fn t<T>(input: T, base: u8, output: &'static str) -> FormatTest<T> {
+    FormatTest {
+        input,
+        base,
+        output,
+    }
+}
+
+const ITOB64_TESTS: &[FormatTest<i64>] = &[
+    t(0, 10, "0"),
+    t(1, 10, "1"),
+    t(-1, 10, "-1"),
+    t(12345678, 10, "12345678"),
+    t(-987654321, 10, "-987654321"),
+    t((1 << 31) - 1, 10, "2147483647"),
+    t(-(1 << 31) + 1, 10, "-2147483647"),
+    t(1 << 31, 10, "2147483648"),
+    t(-(1 << 31), 10, "-2147483648"),
+    t((1 << 31) + 1, 10, "2147483649"),
+    t(-(1 << 31) - 1, 10, "-2147483649"),
+    t((1 << 32) - 1, 10, "4294967295"),
+    t(-(1 << 32) + 1, 10, "-4294967295"),
+    t(1 << 32, 10, "4294967296"),
+    t(-(1 << 32), 10, "-4294967296"),
+    t((1 << 32) + 1, 10, "4294967297"),
+    t(-(1 << 32) - 1, 10, "-4294967297"),
+    t(1 << 50, 10, "1125899906842624"),
+    t(i64::MAX, 10, "9223372036854775807"),
+    t(-i64::MAX, 10, "-9223372036854775807"),
+    t(i64::MIN, 10, "-9223372036854775808"),
+    t(0, 2, "0"),
+    t(10, 2, "1010"),
+    t(-1, 2, "-1"),
+    t(1 << 15, 2, "1000000000000000"),
+    t(-8, 8, "-10"),
+    t(0o57635436545, 8, "57635436545"),
+    t(1 << 24, 8, "100000000"),
+    t(16, 16, "10"),
+    t(-0x123456789abcdef, 16, "-123456789abcdef"),
+    t(i64::MAX, 16, "7fffffffffffffff"),
+    t(
+        i64::MAX,
+        2,
+        "111111111111111111111111111111111111111111111111111111111111111",
+    ),
+    t(
+        i64::MIN,
+        2,
+        "-1000000000000000000000000000000000000000000000000000000000000000",
+    ),
+    t(16, 17, "g"),
+    t(25, 25, "10"),
+    t(
+        (((((17 * 35 + 24) * 35 + 21) * 35 + 34) * 35 + 12) * 35 + 24) * 35 + 32,
+        35,
+        "holycow",
+    ),
+    t(
+        (((((17 * 36 + 24) * 36 + 21) * 36 + 34) * 36 + 12) * 36 + 24) * 36 + 32,
+        36,
+        "holycow",
+    ),
+];
+
+const UITOB64_TESTS: &[FormatTest<u64>] = &[
+    t((1 << 63) - 1, 10, "9223372036854775807"),
+    t(1 << 63, 10, "9223372036854775808"),
+    t((1 << 63) + 1, 10, "9223372036854775809"),
+    t(u64::MAX - 1, 10, "18446744073709551614"),
+    t(u64::MAX, 10, "18446744073709551615"),
+    t(
+        u64::MAX,
+        2,
+        "1111111111111111111111111111111111111111111111111111111111111111",
+    ),
+];
+
+#[test]
+fn format_int() {
+    for c in ITOB64_TESTS {
+        let got = strconv::format_int(c.input, c.base);
+        assert_eq!(got, c.output, "format_int({}, {})", c.input, c.base);
+
+        let mut got = b"abc".to_vec();
+        strconv::append_int(&mut got, c.input, c.base);
+        assert_eq!(
+            got,
+            format!("abc{}", c.output).as_bytes(),
+            "append_int(abc, {}, {})",
+            c.input,
+            c.base
+        );
+
+        if c.input >= 0 {
+            let got = strconv::format_uint(c.input as u64, c.base);
+            assert_eq!(got, c.output, "format_uint({}, {})", c.input, c.base);
+
+            let mut got = b"abc".to_vec();
+            strconv::append_uint(&mut got, c.input as u64, c.base);
+            assert_eq!(
+                got,
+                format!("abc{}", c.output).as_bytes(),
+                "append_uint(abc, {}, {})",
+                c.input,
+                c.base
+            );
+        }
+    }
+}
+
+#[test]
+fn format_uint() {
+    for c in UITOB64_TESTS {
+        let got = strconv::format_uint(c.input, c.base);
+        assert_eq!(got, c.output, "format_uint({}, {})", c.input, c.base);
+
+        let mut got = b"abc".to_vec();
+        strconv::append_uint(&mut got, c.input, c.base);
+        assert_eq!(
+            got,
+            format!("abc{}", c.output).as_bytes(),
+            "append_uint(abc, {}, {})",
+            c.input,
+            c.base
+        );
+
+        if c.input <= i64::MAX as u64 {
+            let got = strconv::format_int(c.input as i64, c.base);
+            assert_eq!(got, c.output, "format_int({}, {})", c.input, c.base);
+        }
+    }
+}
+
+#[test]
+fn format_twos_complement() {
+    let tests = [
+        (-1, 16, 32, "ffffffff"),
+        (-1, 2, 8, "11111111"),
+        (-2, 2, 8, "11111110"),
+        (i32::MIN as i64, 16, 32, "80000000"),
+        (0, 16, 32, "00000000"),
+        (42, 16, 16, "002a"),
+        (5, 2, 3, "101"),
+        (-1, 8, 32, "37777777777"),
+        (-1, 16, 4, "f"),
+        (i64::MIN, 16, 64, "8000000000000000"),
+        (i64::MAX, 16, 64, "7fffffffffffffff"),
+        (-1, 32, 64, "fvvvvvvvvvvvv"),
+        // Truncated as i64 as u16 would be.
+        (0x12345, 16, 16, "2345"),
+    ];
+    for (input, base, bit_size, output) in tests {
+        let got = strconv::format_twos_complement(input, base, bit_size);
+        assert_eq!(
+            got, output,
+            "format_twos_complement({}, {}, {})",
+            input, base, bit_size
+        );
+    }
+}
+
+#[test]
+fn twos_complement_round_trip() {
+    for bit_size in 1..=64u8 {
+        let min = -1i64 << (bit_size - 1);
+        let max = !min;
+        for v in [min, min + 1, -1, 0, 1, max - 1, max] {
+            if (v < min) || (v > max) {
+                continue;
+            }
+            for (base, prefix) in [(2, "0b"), (8, "0o"), (16, "0x")] {
+                let s = strconv::format_twos_complement(v, base, bit_size);
+                assert_eq!(
+                    strconv::parse_twos_complement(&s, base, bit_size),
+                    Ok(v),
+                    "{} in base {} at {} bits",
+                    s,
+                    base,
+                    bit_size
+                );
+
+                let s = format!("{}{}", prefix, s);
+                assert_eq!(strconv::parse_twos_complement(&s, 0, bit_size), Ok(v));
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "strconv.format_twos_complement: invalid base 10")]
+fn format_twos_complement_invalid_base() {
+    strconv::format_twos_complement(-1, 10, 32);
+}
+
+#[test]
+fn itoa() {
+    for c in ITOB64_TESTS.iter().filter(|c| c.base == 10) {
+        if let Ok(i) = isize::try_from(c.input) {
+            assert_eq!(strconv::itoa(i), c.output, "itoa({})", i);
+        }
+    }
+
+    assert_eq!(strconv::itoa(isize::MIN), isize::MIN.to_string());
+    assert_eq!(strconv::atoi(&strconv::itoa(isize::MAX)), Ok(isize::MAX));
+}
+
+#[test]
+fn format_uint_varlen() {
+    let mut expect = String::from("1");
+    let mut u = 1u64;
+    while let Some(v) = u.checked_mul(10) {
+        expect.push('0');
+        u = v;
+        assert_eq!(strconv::format_uint(u, 10), expect);
+
+        let s = (u - 1).to_string();
+        assert_eq!(strconv::format_uint(u - 1, 10), s);
+    }
+}
+
+#[test]
+fn format_small_and_round_trip() {
+    for i in -200i64..200 {
+        assert_eq!(strconv::format_int(i, 10), i.to_string());
+    }
+
+    for base in 2..=36 {
+        for v in [0, 1, 35, 36, 1295, i64::MAX, i64::MIN, -12345678] {
+            let s = strconv::format_int(v, base);
+            assert_eq!(strconv::parse_int(&s, base, 64), Ok(v), "{}", s);
+        }
+        let s = strconv::format_uint(u64::MAX, base);
+        assert_eq!(strconv::parse_uint(&s, base, 64), Ok(u64::MAX), "{}", s);
+    }
+}
+
+#[test]
+fn write_fmt() {
+    use std::fmt::Write;
+
+    for c in ITOB64_TESTS {
+        let mut got = String::from("abc");
+        strconv::write_int(&mut got, c.input, c.base).unwrap();
+        assert_eq!(got, format!("abc{}", c.output));
+    }
+
+    for c in UITOB64_TESTS {
+        let mut got = String::new();
+        strconv::write_uint(&mut got, c.input, c.base).unwrap();
+        assert_eq!(got, c.output);
+    }
+
+    // The adapters work through trait objects too.
+    let mut got = String::new();
+    let w: &mut dyn Write = &mut got;
+    strconv::write_int(w, -255, 16).unwrap();
+    w.write_char(',').unwrap();
+    strconv::write_uint(w, 255, 2).unwrap();
+    assert_eq!(got, "-ff,11111111");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn write_io() {
+    for c in ITOB64_TESTS {
+        let mut got = b"abc".to_vec();
+        strconv::write_int_io(&mut got, c.input, c.base).unwrap();
+        assert_eq!(got, format!("abc{}", c.output).as_bytes());
+    }
+
+    for c in UITOB64_TESTS {
+        let mut got = std::io::Cursor::new(Vec::new());
+        strconv::write_uint_io(&mut got, c.input, c.base).unwrap();
+        assert_eq!(got.into_inner(), c.output.as_bytes());
+    }
+
+    // Errors of the writer are passed on.
+    let mut full = [0u8; 4];
+    let err = strconv::write_int_io(&mut &mut full[..], -12345, 10).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::WriteZero);
+}
+
+#[test]
+#[should_panic(expected = "strconv.append_int: invalid base 0")]
+fn append_int_invalid_base() {
+    strconv::append_int(&mut Vec::new(), 10, 0);
+}
+
+#[test]
+#[should_panic(expected = "strconv.format_int: invalid base 1")]
+fn format_int_invalid_base() {
+    strconv::format_int(10, 1);
+}
+
+#[test]
+#[should_panic(expected = "strconv.format_uint: invalid base 37")]
+fn format_uint_invalid_base() {
+    strconv::format_uint(10, 37);
+}
+
+/// Returns 0, the powers of base and their neighbors up to u128::MAX.
+fn edge_values(base: u8) -> Vec<u128> {
+    let mut values = vec![0, 1, u64::MAX as u128, u64::MAX as u128 + 1, u128::MAX];
+    let mut p = base as u128;
+    loop {
+        values.extend([p - 1, p, p + 1]);
+        match p.checked_mul(base as u128) {
+            Some(next) => p = next,
+            None => break,
+        }
+    }
+    values
+}
+
+#[test]
+fn digit_count() {
+    let mut buf = strconv::FormatBuffer::new();
+    for base in 2..=36 {
+        for u in edge_values(base) {
+            let want = buf.format(u, base).len();
+            assert_eq!(
+                strconv::digit_count(u, base),
+                want,
+                "digit_count({}, {})",
+                u,
+                base
+            );
+
+            if let Ok(v) = u64::try_from(u) {
+                assert_eq!(strconv::digit_count(v, base), want);
+                assert_eq!(strconv::format_uint(v, base).len(), want);
+            }
+            if let Ok(v) = u8::try_from(u) {
+                assert_eq!(strconv::digit_count(v, base), want);
+            }
+        }
+    }
+}
+
+#[test]
+fn formatted_len() {
+    for base in 2..=36 {
+        let mut values = vec![i64::MIN, i64::MIN + 1, i64::MAX];
+        for u in edge_values(base) {
+            if let Ok(v) = i64::try_from(u) {
+                values.extend([v, -v]);
+            }
+        }
+        for i in values {
+            let want = strconv::format_int(i, base).len();
+            assert_eq!(
+                strconv::formatted_len(i, base),
+                want,
+                "formatted_len({}, {})",
+                i,
+                base
+            );
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "strconv.digit_count: invalid base 1")]
+fn digit_count_invalid_base() {
+    strconv::digit_count(10u32, 1);
+}
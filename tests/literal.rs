@@ -0,0 +1,162 @@
+// The literals are returned as String, which requires alloc.
+#![cfg(feature = "alloc")]
+
+use strconv::LiteralStyle;
+
+struct LiteralTest {
+    input: i64,
+    style: LiteralStyle,
+    output: &'static str,
+}
+
+const fn t(input: i64, style: LiteralStyle, output: &'static str) -> LiteralTest {
+    LiteralTest {
+        input,
+        style,
+        output,
+    }
+}
+
+const STYLES: [LiteralStyle; 4] = [
+    LiteralStyle::BINARY,
+    LiteralStyle::OCTAL,
+    LiteralStyle::LEGACY_OCTAL,
+    LiteralStyle::HEX,
+];
+
+const FORMAT_LITERAL_TESTS: &[LiteralTest] = &[
+    t(0, LiteralStyle::BINARY, "0b0"),
+    t(0, LiteralStyle::OCTAL, "0o0"),
+    t(0, LiteralStyle::LEGACY_OCTAL, "0"),
+    t(0, LiteralStyle::HEX, "0x0"),
+    t(0, LiteralStyle::HEX.grouped(4), "0x0"),
+    t(5, LiteralStyle::BINARY, "0b101"),
+    t(-5, LiteralStyle::BINARY, "-0b101"),
+    t(8, LiteralStyle::LEGACY_OCTAL, "010"),
+    t(-0o755, LiteralStyle::OCTAL, "-0o755"),
+    t(255, LiteralStyle::HEX, "0xff"),
+    t(0xdeadbeef, LiteralStyle::HEX.grouped(4), "0xdead_beef"),
+    t(0xdeadbeef, LiteralStyle::HEX.grouped(3), "0xde_adb_eef"),
+    t(0xdeadbeef, LiteralStyle::HEX.grouped(8), "0xdeadbeef"),
+    t(
+        0xdeadbeef,
+        LiteralStyle::HEX.grouped(1),
+        "0xd_e_a_d_b_e_e_f",
+    ),
+    t(0b1010_0101, LiteralStyle::BINARY.grouped(4), "0b1010_0101"),
+    t(
+        0o1234567,
+        LiteralStyle::LEGACY_OCTAL.grouped(3),
+        "01_234_567",
+    ),
+    t(
+        i64::MAX,
+        LiteralStyle::HEX.grouped(4),
+        "0x7fff_ffff_ffff_ffff",
+    ),
+    t(
+        i64::MIN,
+        LiteralStyle::HEX.grouped(4),
+        "-0x8000_0000_0000_0000",
+    ),
+    t(i64::MIN, LiteralStyle::OCTAL, "-0o1000000000000000000000"),
+];
+
+#[test]
+fn format_literal() {
+    for c in FORMAT_LITERAL_TESTS {
+        let got = strconv::format_literal(c.input, c.style);
+        assert_eq!(got, c.output, "format_literal({}, {:?})", c.input, c.style);
+        assert_eq!(strconv::parse_int(&got, 0, 64), Ok(c.input), "{}", got);
+    }
+
+    assert_eq!(
+        strconv::format_uint_literal(u64::MAX, LiteralStyle::HEX.grouped(8)),
+        "0xffffffff_ffffffff"
+    );
+}
+
+#[test]
+fn format_literal_round_trip() {
+    let mut seed = 0x2545_f491_4f6c_dd1du64;
+    let mut rand = move || {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        seed
+    };
+
+    let edges = [0, 1, -1, 7, 8, -8, i64::MAX, i64::MIN, i64::MIN + 1];
+    let randoms: Vec<u64> = (0..2000).map(|_| rand()).collect();
+    // Spread the values over all magnitudes.
+    let values = edges
+        .into_iter()
+        .chain(randoms.iter().map(|&v| (v as i64) >> (v % 64)));
+
+    for x in values {
+        for style in STYLES {
+            for group in 0..=9 {
+                let style = style.grouped(group);
+                let s = strconv::format_literal(x, style);
+                assert_eq!(
+                    strconv::parse_int(&s, 0, 64),
+                    Ok(x),
+                    "format_literal({}, {:?}) = {}",
+                    x,
+                    style,
+                    s
+                );
+
+                let u = x as u64;
+                if x >= 0 {
+                    assert_eq!(strconv::format_uint_literal(u, style), s);
+                }
+                let s = strconv::format_uint_literal(u, style);
+                assert_eq!(strconv::parse_uint(&s, 0, 64), Ok(u), "{}", s);
+            }
+        }
+    }
+}
+
+#[test]
+fn literal_formatted_len() {
+    let styles = [
+        LiteralStyle::BINARY,
+        LiteralStyle::OCTAL,
+        LiteralStyle::LEGACY_OCTAL,
+        LiteralStyle::HEX,
+        LiteralStyle::BINARY.grouped(4),
+        LiteralStyle::OCTAL.grouped(3),
+        LiteralStyle::LEGACY_OCTAL.grouped(1),
+        LiteralStyle::HEX.grouped(2),
+        LiteralStyle::HEX.grouped(5),
+    ];
+    let mut values = vec![0, 1, -1, i64::MIN, i64::MIN + 1, i64::MAX];
+    for shift in 1..63 {
+        let p = 1i64 << shift;
+        values.extend([p - 1, p, p + 1, -p]);
+    }
+
+    for style in styles {
+        for &i in &values {
+            let want = strconv::format_literal(i, style).len();
+            assert_eq!(
+                style.formatted_len(i),
+                want,
+                "{:?}.formatted_len({})",
+                style,
+                i
+            );
+
+            let u = i as u64;
+            let want = strconv::format_uint_literal(u, style).len();
+            assert_eq!(
+                style.formatted_uint_len(u),
+                want,
+                "{:?}.formatted_uint_len({})",
+                style,
+                u
+            );
+        }
+    }
+}
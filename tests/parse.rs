@@ -0,0 +1,241 @@
+// The expected errors record num as Cow<str>, which requires alloc.
+#![cfg(feature = "alloc")]
+
+use std::fmt::Debug;
+
+use strconv::{NumError, NumErrorCause, ParseRadix};
+
+struct ParseTest<T> {
+    input: &'static str,
+    base: u8,
+    output: Result<T, NumError<'static>>,
+}
+
+impl<T> ParseTest<T> {
+    fn ok(input: &'static str, base: u8, output: T) -> Self {
+        Self {
+            input,
+            base,
+            output: Ok(output),
+        }
+    }
+
+    fn err(input: &'static str, base: u8, err: NumErrorCause) -> Self {
+        let err = NumError {
+            func: "parse_uint",
+            num: input.into(),
+            err,
+            invalid: None,
+        };
+
+        Self {
+            input,
+            base,
+            output: Err(err),
+        }
+    }
+
+    fn err_signed(input: &'static str, base: u8, err: NumErrorCause) -> Self {
+        let err = NumError {
+            func: "parse_int",
+            num: input.into(),
+            err,
+            invalid: None,
+        };
+
+        Self {
+            input,
+            base,
+            output: Err(err),
+        }
+    }
+}
+
+fn unlocated(err: NumError) -> NumError {
+    NumError {
+        invalid: None,
+        ..err
+    }
+}
+
+fn run<T>(test_vector: &[ParseTest<T>])
+where
+    T: ParseRadix + PartialEq + Debug,
+{
+    for c in test_vector {
+        let got = strconv::parse::<T>(c.input, c.base).map_err(unlocated);
+        assert_eq!(got, c.output, "parse({}, {})", c.input, c.base);
+
+        let got = strconv::parse_bytes::<T>(c.input.as_bytes(), c.base).map_err(unlocated);
+        assert_eq!(got, c.output, "parse_bytes({}, {})", c.input, c.base);
+    }
+}
+
+#[test]
+fn parse_i8() {
+    let test_vector = vec![
+        ParseTest::ok("0", 10, 0i8),
+        ParseTest::ok("127", 10, i8::MAX),
+        ParseTest::ok("-128", 10, i8::MIN),
+        ParseTest::ok("-0x80", 0, i8::MIN),
+        ParseTest::ok("0b_0111_1111", 0, i8::MAX),
+        ParseTest::err_signed(
+            "128",
+            10,
+            NumErrorCause::OutOfRangeSigned { bound_hint: 127 },
+        ),
+        ParseTest::err_signed(
+            "-129",
+            10,
+            NumErrorCause::OutOfRangeSigned { bound_hint: -128 },
+        ),
+        ParseTest::err_signed("12a", 10, NumErrorCause::InvalidSyntax),
+        ParseTest::err_signed("1", 1, NumErrorCause::InvalidBase(1)),
+    ];
+
+    run(&test_vector);
+}
+
+#[test]
+fn parse_i16_i32_i64() {
+    run(&[
+        ParseTest::ok("-32768", 10, i16::MIN),
+        ParseTest::ok("7fff", 16, i16::MAX),
+        ParseTest::err_signed(
+            "32768",
+            10,
+            NumErrorCause::OutOfRangeSigned { bound_hint: 32767 },
+        ),
+    ]);
+
+    run(&[
+        ParseTest::ok("-2147483648", 10, i32::MIN),
+        ParseTest::ok("0x7fffffff", 0, i32::MAX),
+        ParseTest::err_signed(
+            "-2147483649",
+            10,
+            NumErrorCause::OutOfRangeSigned {
+                bound_hint: i32::MIN as i64,
+            },
+        ),
+    ]);
+
+    run(&[
+        ParseTest::ok("-9223372036854775808", 10, i64::MIN),
+        ParseTest::ok("9223372036854775807", 10, i64::MAX),
+        ParseTest::err_signed(
+            "9223372036854775808",
+            10,
+            NumErrorCause::OutOfRangeSigned {
+                bound_hint: i64::MAX,
+            },
+        ),
+    ]);
+}
+
+#[test]
+fn parse_isize() {
+    let max = isize::MAX.to_string();
+    let min = isize::MIN.to_string();
+
+    assert_eq!(strconv::parse::<isize>(&max, 10), Ok(isize::MAX));
+    assert_eq!(strconv::parse::<isize>(&min, 10), Ok(isize::MIN));
+    assert_eq!(strconv::parse::<isize>("-0o17", 0), Ok(-0o17));
+}
+
+#[test]
+fn parse_u8() {
+    let test_vector = vec![
+        ParseTest::ok("0", 10, 0u8),
+        ParseTest::ok("255", 10, u8::MAX),
+        ParseTest::ok("0xff", 0, u8::MAX),
+        ParseTest::ok("0o377", 0, u8::MAX),
+        ParseTest::err(
+            "256",
+            10,
+            NumErrorCause::OutOfRangeUnsigned { bound_hint: 255 },
+        ),
+        ParseTest::err("-1", 10, NumErrorCause::InvalidSyntax),
+        ParseTest::err("", 10, NumErrorCause::InvalidSyntax),
+    ];
+
+    run(&test_vector);
+}
+
+#[test]
+fn parse_u16_u32_u64() {
+    run(&[
+        ParseTest::ok("65535", 10, u16::MAX),
+        ParseTest::err(
+            "65536",
+            10,
+            NumErrorCause::OutOfRangeUnsigned { bound_hint: 65535 },
+        ),
+    ]);
+
+    run(&[
+        ParseTest::ok("4294967295", 10, u32::MAX),
+        ParseTest::ok("0xffff_ffff", 0, u32::MAX),
+        ParseTest::err(
+            "4294967296",
+            10,
+            NumErrorCause::OutOfRangeUnsigned {
+                bound_hint: u32::MAX as u64,
+            },
+        ),
+    ]);
+
+    run(&[
+        ParseTest::ok("18446744073709551615", 10, u64::MAX),
+        ParseTest::err(
+            "18446744073709551616",
+            10,
+            NumErrorCause::OutOfRangeUnsigned {
+                bound_hint: u64::MAX,
+            },
+        ),
+    ]);
+}
+
+#[test]
+fn parse_usize() {
+    let max = usize::MAX.to_string();
+
+    assert_eq!(strconv::parse::<usize>(&max, 10), Ok(usize::MAX));
+    assert_eq!(strconv::parse::<usize>("0b101", 0), Ok(5));
+}
+
+#[test]
+fn bound_hint() {
+    let err = strconv::parse::<i8>("-200", 10).unwrap_err();
+    assert_eq!(err.err.bound_hint::<i8>(), Some(i8::MIN));
+    assert_eq!(err.err.bound_hint::<u8>(), None);
+
+    let err = strconv::parse::<u32>("0x1_0000_0000", 0).unwrap_err();
+    assert_eq!(err.err.bound_hint::<u32>(), Some(u32::MAX));
+    assert_eq!(err.err.bound_hint::<u16>(), None);
+    assert_eq!(err.err.bound_hint::<i64>(), None);
+
+    let err = strconv::parse::<u32>("x", 0).unwrap_err();
+    assert_eq!(err.err.bound_hint::<u32>(), None);
+}
+
+#[test]
+fn parse_128() {
+    assert_eq!(
+        strconv::parse::<u128>("0xffffffffffffffffffffffffffffffff", 0),
+        Ok(u128::MAX)
+    );
+    assert_eq!(
+        strconv::parse::<i128>("-170141183460469231731687303715884105728", 10),
+        Ok(i128::MIN)
+    );
+
+    let err = strconv::parse::<u128>("340282366920938463463374607431768211456", 10).unwrap_err();
+    assert_eq!(err.func, "parse_uint128");
+    assert_eq!(err.err.bound_hint::<u128>(), Some(u128::MAX));
+    assert_eq!(err.err.bound_hint::<u64>(), None);
+
+    let err = strconv::parse::<i128>("-0x8000_0000_0000_0000_0000_0000_0000_0001", 0).unwrap_err();
+    assert_eq!(err.err.bound_hint::<i128>(), Some(i128::MIN));
+}
@@ -0,0 +1,144 @@
+#![cfg(feature = "alloc")]
+
+use strconv::{radix, FormatBuffer};
+
+const INTS: &[i64] = &[
+    0,
+    1,
+    -1,
+    9,
+    10,
+    35,
+    36,
+    99,
+    100,
+    -100,
+    255,
+    -256,
+    12345678,
+    -987654321,
+    i32::MIN as i64,
+    i32::MAX as i64,
+    i64::MIN + 1,
+    i64::MIN,
+    i64::MAX,
+];
+
+#[test]
+fn radix_matches_format_int() {
+    for &v in INTS {
+        for base in 2..=36 {
+            let want = strconv::format_int(v, base);
+            assert_eq!(radix(v, base).to_string(), want, "radix({}, {})", v, base);
+            assert_eq!(format!("{:?}", radix(v, base)), want);
+            assert_eq!(radix(v, base).uppercase().to_string(), want.to_uppercase());
+            if base == 16 {
+                assert_eq!(format!("{:x}", radix(v, base)), want);
+                assert_eq!(format!("{:X}", radix(v, base)), want.to_uppercase());
+            }
+
+            if let Ok(w) = i8::try_from(v) {
+                assert_eq!(radix(w, base).to_string(), want);
+            }
+            if let Ok(w) = i16::try_from(v) {
+                assert_eq!(radix(w, base).to_string(), want);
+            }
+            if let Ok(w) = i32::try_from(v) {
+                assert_eq!(radix(w, base).to_string(), want);
+            }
+            if let Ok(w) = isize::try_from(v) {
+                assert_eq!(radix(w, base).to_string(), want);
+            }
+            assert_eq!(radix(v as i128, base).to_string(), want);
+        }
+    }
+}
+
+#[test]
+fn radix_matches_format_uint() {
+    for &v in INTS {
+        let v = v as u64;
+        for base in 2..=36 {
+            let want = strconv::format_uint(v, base);
+            assert_eq!(radix(v, base).to_string(), want, "radix({}, {})", v, base);
+
+            if let Ok(w) = u8::try_from(v) {
+                assert_eq!(radix(w, base).to_string(), want);
+            }
+            if let Ok(w) = u16::try_from(v) {
+                assert_eq!(radix(w, base).to_string(), want);
+            }
+            if let Ok(w) = u32::try_from(v) {
+                assert_eq!(radix(w, base).to_string(), want);
+            }
+            if let Ok(w) = usize::try_from(v) {
+                assert_eq!(radix(w, base).to_string(), want);
+            }
+            assert_eq!(radix(v as u128, base).to_string(), want);
+        }
+    }
+
+    let mut buf = FormatBuffer::new();
+    for base in 2..=36 {
+        for v in [i128::MIN, i128::MAX] {
+            assert_eq!(radix(v, base).to_string(), buf.format(v, base));
+        }
+        assert_eq!(
+            radix(u128::MAX, base).to_string(),
+            buf.format(u128::MAX, base)
+        );
+    }
+}
+
+#[test]
+fn radix_flags() {
+    // The flags apply as to the primitive integers.
+    for &v in INTS {
+        let r = radix(v, 10);
+        assert_eq!(format!("{:12}", r), format!("{:12}", v));
+        assert_eq!(format!("{:<12}", r), format!("{:<12}", v));
+        assert_eq!(format!("{:^12}", r), format!("{:^12}", v));
+        assert_eq!(format!("{:_>12}", r), format!("{:_>12}", v));
+        assert_eq!(format!("{:+}", r), format!("{:+}", v));
+        assert_eq!(format!("{:+012}", r), format!("{:+012}", v));
+        assert_eq!(format!("{:<+012}", r), format!("{:<+012}", v));
+
+        let u = v as u64;
+        assert_eq!(format!("{:#}", radix(u, 16)), format!("{:#x}", u));
+        let r = radix(u, 16).uppercase();
+        assert_eq!(format!("{:#020}", r), format!("{:#020X}", u));
+        assert_eq!(format!("{:#x}", radix(u, 16)), format!("{:#x}", u));
+        assert_eq!(format!("{:#020X}", radix(u, 16)), format!("{:#020X}", u));
+        assert_eq!(format!("{:#}", radix(u, 8)), format!("{:#o}", u));
+        assert_eq!(format!("{:>#70}", radix(u, 2)), format!("{:>#70b}", u));
+    }
+
+    assert_eq!(format!("{:>12}", radix(-1295, 36)), "         -zz");
+    assert_eq!(format!("{:#012}", radix(-255, 16)), "-0x0000000ff");
+    assert_eq!(format!("{:#}", radix(35, 36)), "z");
+    assert_eq!(format!("{:#}", radix(0xbeefu16, 16).uppercase()), "0xBEEF");
+    assert_eq!(format!("{:08}", radix(-0xbeef, 16).uppercase()), "-000BEEF");
+    assert_eq!(format!("{:+.3}", radix(7u8, 3)), "+21");
+}
+
+#[test]
+fn radix_accessors() {
+    let r = radix(-42i8, 7);
+    assert_eq!(r.value(), -42);
+    assert_eq!(r.base(), 7);
+    assert_eq!(r, radix(-42i8, 7));
+    assert_ne!(r, r.uppercase());
+    assert_eq!(r.uppercase().value(), -42);
+}
+
+#[test]
+#[should_panic(expected = "strconv.radix: invalid base 37")]
+fn radix_invalid_base() {
+    radix(1, 37);
+}
+
+#[test]
+#[should_panic(expected = "strconv.radix: invalid base 10 for hex formatting")]
+fn radix_hex_invalid_base() {
+    let _ = format!("{:x}", radix(255, 10));
+}